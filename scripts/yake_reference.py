@@ -0,0 +1,114 @@
+# Copyright (C) 2024 Afonso Barracha
+#
+# Rust Keyword Extraction is free software: you can redistribute it and/or modify
+# it under the terms of the GNU Lesser General Public License as published by
+# the Free Software Foundation, either version 3 of the License, or
+# (at your option) any later version.
+#
+# Rust Keyword Extraction is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+# GNU Lesser General Public License for more details.
+#
+# You should have received a copy of the GNU Lesser General Public License
+# along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+"""Prints the YAKE fixtures of `src/tests.rs` from the reference Python package.
+
+Usage, from the repository root:
+
+    pip install yake==0.4.8
+    cargo fetch
+    python3 scripts/yake_reference.py
+
+The conformance cases run with lan="en", n=3, dedupLim=1.0 (no deduplication),
+dedupFunc="seqm", the windowsSize of each case and the stop words of the tests
+(the ISO English list of the stop-words crate, without "c" and "computer").
+Scores are normalised as min(H) / H, like `Yake::get_keyword_score`.
+
+The README example runs with the reference settings and its own stop words:
+lan="en", n=3, dedupLim=0.9, dedupFunc="seqm", windowsSize=1, top=20.
+"""
+
+import json
+import re
+import subprocess
+from importlib.metadata import version
+from pathlib import Path
+
+import yake
+
+CONFORMANCE_CASES = [
+    (
+        "Rust is a systems programming language. Rust programs are fast and memory safe. "
+        "The Rust compiler checks memory safety at compile time. Systems programmers choose "
+        "Rust for speed and safety of memory.",
+        1,
+        11,
+    ),
+    (
+        "The NASA mission launched in 2024 from Florida. NASA engineers tested the Orion "
+        "capsule twice, then again. The Orion capsule carried 4 astronauts around the Moon. "
+        "Engineers at NASA celebrated the successful Orion mission.",
+        2,
+        10,
+    ),
+    (
+        "Rust developers write fast code. A Rust developer reviews code daily. Senior Rust "
+        "developers mentor the team, and every Rust developer learns quickly.",
+        1,
+        7,
+    ),
+]
+
+
+def get_stop_words():
+    metadata = json.loads(
+        subprocess.check_output(["cargo", "metadata", "--format-version", "1"])
+    )
+    manifest = next(
+        package["manifest_path"]
+        for package in metadata["packages"]
+        if package["name"] == "stop-words"
+    )
+    iso = Path(manifest).parent / "src" / "iso" / "stopwords-iso.json"
+    words = {word.replace('"', "") for word in json.loads(iso.read_text())["en"]}
+    return words - {"c", "computer"}
+
+
+def print_conformance_cases():
+    stop_words = get_stop_words()
+
+    for text, window_size, top in CONFORMANCE_CASES:
+        extractor = yake.KeywordExtractor(
+            lan="en",
+            n=3,
+            dedupLim=1.0,
+            dedupFunc="seqm",
+            windowsSize=window_size,
+            top=top,
+            stopwords=stop_words,
+        )
+        keywords = extractor.extract_keywords(text)
+        best = min(score for _, score in keywords)
+        print(f"// windowsSize = {window_size}")
+        for keyword, score in keywords:
+            print(f'("{keyword.lower()}", {best / score:.6f}),')
+
+
+def print_readme_example():
+    tests = (Path(__file__).parent.parent / "src" / "tests.rs").read_text()
+    text = re.search(r'const YAKE_README_TEXT: &str =\s*"(.*?)";', tests, re.S).group(1)
+    text = re.sub(r"\\\n\s*", "", text)
+    extractor = yake.KeywordExtractor(
+        lan="en", n=3, dedupLim=0.9, dedupFunc="seqm", windowsSize=1, top=20
+    )
+    print("// README example")
+    for keyword, score in extractor.extract_keywords(text):
+        print(f'("{keyword.lower()}", {score:.6f}),')
+
+
+if __name__ == "__main__":
+    print(f"// yake {version('yake')}")
+    print_conformance_cases()
+    print_readme_example()
@@ -0,0 +1,151 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+/// The measure used to score how strongly two words are associated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssociationMeasure {
+    /// Co-occurrence count divided by the largest count of the matrix.
+    Normalized,
+
+    /// Pointwise mutual information: ln(p(x, y) / (p(x) p(y))).
+    Pmi,
+
+    /// Normalised pointwise mutual information: PMI / -ln(p(x, y)), between -1 and 1.
+    Npmi,
+
+    /// Positive pointwise mutual information: max(PMI, 0).
+    Ppmi,
+
+    /// Dice coefficient: 2 c(x, y) / (f(x) + f(y)), with the word frequencies f, capped at 1.
+    Dice,
+
+    /// Jaccard index: c(x, y) / (f(x) + f(y) - c(x, y)), with the word frequencies f, capped at 1.
+    Jaccard,
+
+    /// Dunning's log-likelihood ratio (G²) of the 2×2 contingency table of the word frequencies,
+    /// out of the number of vocabulary tokens.
+    LogLikelihood,
+}
+
+/// The counts needed to score the association between two words.
+///
+/// The PMI measures use the marginals of the co-occurrence matrix, while Dice, Jaccard and
+/// log-likelihood use the word frequencies, since with windows larger than 1 each occurrence
+/// of a word adds to several co-occurrences.
+pub struct AssociationCounts {
+    /// Number of co-occurrences of both words.
+    pub pair: f32,
+    /// Marginal count of the first word (sum of its row).
+    pub word1: f32,
+    /// Marginal count of the second word (sum of its column).
+    pub word2: f32,
+    /// Sum of all the co-occurrence counts.
+    pub total: f32,
+    /// Number of occurrences of the first word.
+    pub frequency1: f32,
+    /// Number of occurrences of the second word.
+    pub frequency2: f32,
+    /// Number of occurrences of all the vocabulary words.
+    pub tokens: f32,
+    /// Largest co-occurrence count.
+    pub max: f32,
+}
+
+fn x_ln_x_ratio(k: f32, expected: f32) -> f32 {
+    if k <= 0.0 || expected <= 0.0 {
+        0.0
+    } else {
+        k * (k / expected).ln()
+    }
+}
+
+fn pmi(counts: &AssociationCounts) -> f32 {
+    if counts.pair <= 0.0 {
+        return f32::NEG_INFINITY;
+    }
+
+    (counts.pair * counts.total / (counts.word1 * counts.word2)).ln()
+}
+
+impl AssociationMeasure {
+    /// Scores the association between two words from their counts.
+    pub fn score(&self, counts: &AssociationCounts) -> f32 {
+        match self {
+            AssociationMeasure::Normalized => {
+                if counts.max > 0.0 {
+                    counts.pair / counts.max
+                } else {
+                    0.0
+                }
+            }
+            AssociationMeasure::Pmi => pmi(counts),
+            AssociationMeasure::Npmi => {
+                if counts.pair <= 0.0 {
+                    return -1.0;
+                }
+
+                let joint_probability = counts.pair / counts.total;
+
+                if joint_probability >= 1.0 {
+                    return 1.0;
+                }
+
+                pmi(counts) / -joint_probability.ln()
+            }
+            AssociationMeasure::Ppmi => pmi(counts).max(0.0),
+            AssociationMeasure::Dice => {
+                let frequencies = counts.frequency1 + counts.frequency2;
+
+                if frequencies > 0.0 {
+                    (2.0 * counts.pair / frequencies).min(1.0)
+                } else {
+                    0.0
+                }
+            }
+            AssociationMeasure::Jaccard => {
+                // A word can co-occur more than once per occurrence, so the union is at least the pair.
+                let union = (counts.frequency1 + counts.frequency2 - counts.pair).max(counts.pair);
+
+                if union > 0.0 {
+                    counts.pair / union
+                } else {
+                    0.0
+                }
+            }
+            AssociationMeasure::LogLikelihood => {
+                let k11 = counts.pair;
+                let k12 = (counts.frequency1 - counts.pair).max(0.0);
+                let k21 = (counts.frequency2 - counts.pair).max(0.0);
+                let k22 =
+                    (counts.tokens - counts.frequency1 - counts.frequency2 + counts.pair).max(0.0);
+                let total = k11 + k12 + k21 + k22;
+
+                if total <= 0.0 {
+                    return 0.0;
+                }
+
+                let row1 = k11 + k12;
+                let row2 = k21 + k22;
+                let column1 = k11 + k21;
+                let column2 = k12 + k22;
+
+                2.0 * (x_ln_x_ratio(k11, row1 * column1 / total)
+                    + x_ln_x_ratio(k12, row1 * column2 / total)
+                    + x_ln_x_ratio(k21, row2 * column1 / total)
+                    + x_ln_x_ratio(k22, row2 * column2 / total))
+            }
+        }
+    }
+}
@@ -0,0 +1,296 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+
+use crate::common::next_random;
+
+const MAX_PASSES: usize = 100;
+const MIN_GAIN: f32 = 1e-6;
+const SEED_OFFSET: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Weighted adjacency lists of an undirected graph, without self loops unless aggregated.
+type Adjacency = Vec<Vec<(usize, f32)>>;
+
+/// The community detection algorithm used to cluster the words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommunityDetection {
+    /// Louvain modularity optimisation, merging communities while the modularity improves.
+    Louvain,
+
+    /// Label propagation, each word takes the label with the largest weight among its neighbours.
+    LabelPropagation,
+}
+
+/// The options of the word clustering.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClusteringOptions {
+    /// The community detection algorithm, defaults to `CommunityDetection::Louvain`.
+    pub algorithm: CommunityDetection,
+    /// The seed of the order in which words are visited, defaults to 42.
+    pub seed: u64,
+    /// The number of most central words used as the cluster label, defaults to 3.
+    pub label_size: usize,
+}
+
+impl Default for ClusteringOptions {
+    fn default() -> Self {
+        Self {
+            algorithm: CommunityDetection::Louvain,
+            seed: 42,
+            label_size: 3,
+        }
+    }
+}
+
+/// A cluster of words found by community detection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordCluster {
+    /// The label of the cluster, its most central words separated by commas.
+    pub label: String,
+    /// The words of the cluster with their centrality (weighted degree inside the cluster), sorted by centrality.
+    pub words: Vec<(String, f32)>,
+}
+
+fn get_shuffled_order(size: usize, state: &mut u64) -> Vec<usize> {
+    let mut order = (0..size).collect::<Vec<usize>>();
+    (1..size).rev().for_each(|i| {
+        let j = (next_random(state) % (i as u64 + 1)) as usize;
+        order.swap(i, j);
+    });
+    order
+}
+
+fn get_neighbour_weights(graph: &Adjacency, node: usize, labels: &[usize]) -> BTreeMap<usize, f32> {
+    graph[node].iter().filter(|(other, _)| *other != node).fold(
+        BTreeMap::new(),
+        |mut acc, (other, weight)| {
+            *acc.entry(labels[*other]).or_insert(0.0) += weight;
+            acc
+        },
+    )
+}
+
+fn renumber(labels: &[usize]) -> Vec<usize> {
+    let mut ids = vec![usize::MAX; labels.len()];
+    let mut count = 0;
+
+    labels
+        .iter()
+        .map(|label| {
+            if ids[*label] == usize::MAX {
+                ids[*label] = count;
+                count += 1;
+            }
+
+            ids[*label]
+        })
+        .collect()
+}
+
+fn label_propagation(graph: &Adjacency, state: &mut u64) -> Vec<usize> {
+    let mut labels = (0..graph.len()).collect::<Vec<usize>>();
+
+    for _ in 0..MAX_PASSES {
+        let mut changed = false;
+
+        for node in get_shuffled_order(graph.len(), state) {
+            let weights = get_neighbour_weights(graph, node, &labels);
+            let current = labels[node];
+            let (best, _) = weights.iter().fold(
+                (current, *weights.get(&current).unwrap_or(&0.0)),
+                |(best, best_weight), (label, weight)| {
+                    if *weight > best_weight {
+                        (*label, *weight)
+                    } else {
+                        (best, best_weight)
+                    }
+                },
+            );
+
+            if best != current {
+                labels[node] = best;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    renumber(&labels)
+}
+
+/// Moves each node to the neighbouring community with the largest modularity gain.
+fn move_nodes(graph: &Adjacency, state: &mut u64) -> (Vec<usize>, bool) {
+    let size = graph.len();
+    let mut communities = (0..size).collect::<Vec<usize>>();
+    let degrees = graph
+        .iter()
+        .map(|row| row.iter().map(|(_, weight)| weight).sum::<f32>())
+        .collect::<Vec<f32>>();
+    let total = degrees.iter().sum::<f32>();
+
+    if total <= 0.0 {
+        return (communities, false);
+    }
+
+    let mut totals = degrees.to_vec();
+    let mut moved = false;
+
+    for _ in 0..MAX_PASSES {
+        let mut improved = false;
+
+        for node in get_shuffled_order(size, state) {
+            let weights = get_neighbour_weights(graph, node, &communities);
+            let current = communities[node];
+            totals[current] -= degrees[node];
+            let gain =
+                |community: usize, weight: f32| weight - totals[community] * degrees[node] / total;
+            let (best, _) = weights.iter().fold(
+                (
+                    current,
+                    gain(current, *weights.get(&current).unwrap_or(&0.0)),
+                ),
+                |(best, best_gain), (community, weight)| {
+                    let community_gain = gain(*community, *weight);
+
+                    if community_gain > best_gain + MIN_GAIN {
+                        (*community, community_gain)
+                    } else {
+                        (best, best_gain)
+                    }
+                },
+            );
+            totals[best] += degrees[node];
+
+            if best != current {
+                communities[node] = best;
+                improved = true;
+            }
+        }
+
+        if !improved {
+            break;
+        }
+
+        moved = true;
+    }
+
+    (renumber(&communities), moved)
+}
+
+/// Collapses each community into a single node, keeping the internal weights as self loops.
+fn aggregate(graph: &Adjacency, communities: &[usize]) -> Adjacency {
+    let count = communities.iter().max().map_or(0, |c| c + 1);
+
+    graph
+        .iter()
+        .enumerate()
+        .fold(
+            vec![BTreeMap::<usize, f32>::new(); count],
+            |mut rows, (node, row)| {
+                row.iter().for_each(|(other, weight)| {
+                    *rows[communities[node]]
+                        .entry(communities[*other])
+                        .or_insert(0.0) += weight;
+                });
+                rows
+            },
+        )
+        .into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
+
+fn louvain(graph: &Adjacency, state: &mut u64) -> Vec<usize> {
+    let mut membership = (0..graph.len()).collect::<Vec<usize>>();
+    let mut graph = graph.to_vec();
+
+    for _ in 0..MAX_PASSES {
+        let (communities, moved) = move_nodes(&graph, state);
+
+        if !moved {
+            break;
+        }
+
+        membership
+            .iter_mut()
+            .for_each(|community| *community = communities[*community]);
+        graph = aggregate(&graph, &communities);
+    }
+
+    membership
+}
+
+/// Gets the community of each node of the graph, numbered from 0.
+pub(super) fn detect_communities(
+    graph: &Adjacency,
+    algorithm: CommunityDetection,
+    seed: u64,
+) -> Vec<usize> {
+    let mut state = seed.wrapping_add(SEED_OFFSET).max(1);
+
+    match algorithm {
+        CommunityDetection::Louvain => louvain(graph, &mut state),
+        CommunityDetection::LabelPropagation => label_propagation(graph, &mut state),
+    }
+}
+
+/// Groups the words with at least one neighbour by community, sorted by size.
+pub(super) fn get_word_clusters(
+    graph: &Adjacency,
+    words: &[String],
+    options: &ClusteringOptions,
+) -> Vec<WordCluster> {
+    let communities = detect_communities(graph, options.algorithm, options.seed);
+    let count = communities.iter().max().map_or(0, |c| c + 1);
+    let mut clusters = graph
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| !row.is_empty())
+        .fold(vec![Vec::new(); count], |mut clusters, (node, row)| {
+            let centrality = row
+                .iter()
+                .filter(|(other, _)| communities[*other] == communities[node])
+                .map(|(_, weight)| weight)
+                .sum::<f32>();
+            clusters[communities[node]].push((words[node].to_string(), centrality));
+            clusters
+        })
+        .into_iter()
+        .filter(|cluster| !cluster.is_empty())
+        .map(|mut cluster| {
+            cluster.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            WordCluster {
+                label: cluster
+                    .iter()
+                    .take(options.label_size)
+                    .map(|(word, _)| word.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", "),
+                words: cluster,
+            }
+        })
+        .collect::<Vec<WordCluster>>();
+    clusters.sort_by(|a, b| {
+        b.words
+            .len()
+            .cmp(&a.words.len())
+            .then_with(|| a.label.cmp(&b.label))
+    });
+    clusters
+}
@@ -0,0 +1,233 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::HashMap;
+
+use crate::{
+    common::{Documents, Punctuation, Stopwords, Text},
+    tokenizer::Tokenizer,
+};
+
+use super::{
+    add_window_counts, co_occurrence_params::split_text, create_words_indexes, select_vocabulary,
+    CoOccurrence, CoOccurrenceOptions, SparseMatrix,
+};
+
+/// Accumulates co-occurrence counts one batch of documents at a time, growing the vocabulary on the fly.
+///
+/// Builders with the same options can be merged, so per-thread or per-shard accumulators can be combined
+/// before calling `finalize`.
+#[derive(Clone, Debug, Default)]
+pub struct CoOccurrenceBuilder {
+    options: CoOccurrenceOptions,
+    words: Vec<String>,
+    words_indexes: HashMap<String, usize>,
+    frequencies: Vec<usize>,
+    rows: Vec<HashMap<usize, f32>>,
+    right_rows: Vec<HashMap<usize, f32>>,
+}
+
+fn remap_rows(
+    rows: Vec<HashMap<usize, f32>>,
+    labels: &[Option<usize>],
+    length: usize,
+) -> SparseMatrix {
+    let remapped = rows.into_iter().enumerate().fold(
+        vec![HashMap::<usize, f32>::new(); length],
+        |mut remapped, (i, row)| {
+            if let Some(first_index) = labels[i] {
+                row.into_iter().for_each(|(j, value)| {
+                    if let Some(other_index) = labels[j] {
+                        remapped[first_index].insert(other_index, value);
+                    }
+                });
+            }
+
+            remapped
+        },
+    );
+    SparseMatrix::from_rows(remapped, length)
+}
+
+impl CoOccurrenceBuilder {
+    /// Create a new empty CoOccurrenceBuilder.
+    pub fn new(options: CoOccurrenceOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    fn get_or_insert_label(&mut self, word: &str) -> usize {
+        if let Some(label) = self.words_indexes.get(word) {
+            return *label;
+        }
+
+        let label = self.words.len();
+        self.words.push(word.to_string());
+        self.words_indexes.insert(word.to_string(), label);
+        self.frequencies.push(0);
+        self.rows.push(HashMap::new());
+
+        if self.options.directional {
+            self.right_rows.push(HashMap::new());
+        }
+
+        label
+    }
+
+    /// Adds a batch of pre-processed documents, with words separated by whitespace.
+    pub fn add_documents(&mut self, documents: Documents) -> &mut Self {
+        documents.iter().for_each(|doc| {
+            let labels = doc
+                .split_whitespace()
+                .map(|word| {
+                    let label = self.get_or_insert_label(word);
+                    self.frequencies[label] += 1;
+                    Some(label)
+                })
+                .collect::<Vec<Option<usize>>>();
+            add_window_counts(
+                &mut self.rows,
+                &labels,
+                self.options.window_size,
+                self.options.distance_decay,
+                false,
+            );
+
+            if self.options.directional {
+                add_window_counts(
+                    &mut self.right_rows,
+                    &labels,
+                    self.options.window_size,
+                    self.options.distance_decay,
+                    true,
+                );
+            }
+        });
+        self
+    }
+
+    /// Adds an unprocessed text, split at the window boundary of the options.
+    pub fn add_text(
+        &mut self,
+        text: Text,
+        stop_words: Stopwords,
+        punctuation: Punctuation,
+    ) -> &mut Self {
+        let documents = split_text(
+            &Tokenizer::new(text, stop_words, punctuation),
+            self.options.window_boundary,
+        );
+        self.add_documents(&documents)
+    }
+
+    /// Adds the counts of another builder, the vocabulary options of this builder are the ones kept.
+    ///
+    /// # Panics
+    ///
+    /// If the builders count co-occurrences differently: another window size, distance decay,
+    /// window boundary or directional option.
+    pub fn merge(&mut self, other: CoOccurrenceBuilder) -> &mut Self {
+        let counting = |options: &CoOccurrenceOptions| {
+            (
+                options.window_size,
+                options.distance_decay,
+                options.directional,
+                options.window_boundary,
+            )
+        };
+        assert!(
+            counting(&self.options) == counting(&other.options),
+            "cannot merge co-occurrence builders with different counting options: {:?} and {:?}",
+            self.options,
+            other.options
+        );
+
+        let labels = other
+            .words
+            .iter()
+            .map(|word| self.get_or_insert_label(word))
+            .collect::<Vec<usize>>();
+        let add_rows = |rows: &mut [HashMap<usize, f32>], other_rows: Vec<HashMap<usize, f32>>| {
+            other_rows.into_iter().enumerate().for_each(|(i, row)| {
+                row.into_iter().for_each(|(j, value)| {
+                    *rows[labels[i]].entry(labels[j]).or_insert(0.0) += value;
+                });
+            });
+        };
+        add_rows(&mut self.rows, other.rows);
+
+        if self.options.directional {
+            add_rows(&mut self.right_rows, other.right_rows);
+        }
+
+        other
+            .frequencies
+            .into_iter()
+            .enumerate()
+            .for_each(|(i, frequency)| self.frequencies[labels[i]] += frequency);
+        self
+    }
+
+    /// Number of distinct words seen so far.
+    pub fn get_vocabulary_size(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Builds the queryable co-occurrence matrix, keeping the words allowed by the vocabulary options.
+    ///
+    /// The words are labelled by frequency (then alphabetically), so the result does not depend on
+    /// the order in which batches were added or merged.
+    pub fn finalize(self) -> CoOccurrence {
+        let words = select_vocabulary(
+            self.words
+                .iter()
+                .map(|word| word.as_str())
+                .zip(self.frequencies.iter().copied()),
+            self.options.min_count,
+            self.options.max_vocabulary,
+        );
+        let words_indexes = create_words_indexes(&words);
+        let labels = self
+            .words
+            .iter()
+            .map(|word| words_indexes.get(word).copied())
+            .collect::<Vec<Option<usize>>>();
+        let frequencies = self.frequencies.iter().enumerate().fold(
+            vec![0; words.len()],
+            |mut frequencies, (i, frequency)| {
+                if let Some(label) = labels[i] {
+                    frequencies[label] = *frequency;
+                }
+
+                frequencies
+            },
+        );
+        let right_counts = if self.options.directional {
+            Some(remap_rows(self.right_rows, &labels, words.len()))
+        } else {
+            None
+        };
+
+        CoOccurrence::from_counts(
+            remap_rows(self.rows, &labels, words.len()),
+            right_counts,
+            frequencies,
+            words,
+            words_indexes,
+        )
+    }
+}
@@ -0,0 +1,311 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use std::collections::HashSet;
+
+use crate::{
+    common::{Documents, PhraseLength, Punctuation, Stopwords, Text, WindowSize},
+    tokenizer::Tokenizer,
+};
+
+type Phrases<'a> = &'a [String];
+
+/// The unit of text a co-occurrence window can not cross.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowBoundary {
+    /// Windows stop at the end of each document (or at the end of the text block).
+    Document,
+
+    /// Windows stop at the end of each sentence.
+    Sentences,
+
+    /// Windows stop at the end of each paragraph (line).
+    Paragraphs,
+}
+
+/// The weight a co-occurrence adds depending on the distance `d` between both words.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DistanceDecay {
+    /// Every co-occurrence adds 1.
+    Uniform,
+
+    /// Every co-occurrence adds 1 / d, as in GloVe.
+    Inverse,
+
+    /// Every co-occurrence adds (window_size - d + 1) / window_size.
+    Linear,
+
+    /// Every co-occurrence adds e^(-rate × (d - 1)), with the given rate.
+    Exponential(f32),
+}
+
+impl DistanceDecay {
+    /// Gets the weight of a co-occurrence at the given distance inside a window.
+    pub fn weight(&self, distance: usize, window_size: WindowSize) -> f32 {
+        match self {
+            DistanceDecay::Uniform => 1.0,
+            DistanceDecay::Inverse => 1.0 / distance as f32,
+            DistanceDecay::Linear => {
+                window_size.saturating_add(1).saturating_sub(distance) as f32
+                    / window_size.max(1) as f32
+            }
+            DistanceDecay::Exponential(rate) => (-rate * (distance as f32 - 1.0)).exp(),
+        }
+    }
+}
+
+/// The options used to build the co-occurrence matrix from unprocessed text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CoOccurrenceOptions {
+    /// The number of words to each side of a word that are counted as co-occurring, defaults to 2.
+    ///
+    /// Use `usize::MAX` to relate every pair of words inside the same window boundary.
+    pub window_size: WindowSize,
+    /// The weight of each co-occurrence given the distance between both words, defaults to `DistanceDecay::Uniform`.
+    pub distance_decay: DistanceDecay,
+    /// Whether to keep the left and right context matrices separately, defaults to false.
+    pub directional: bool,
+    /// The unit of text a window can not cross, defaults to `WindowBoundary::Document`.
+    pub window_boundary: WindowBoundary,
+    /// The minimum number of occurrences for a word to be part of the vocabulary, defaults to 1.
+    pub min_count: usize,
+    /// Optional maximum size of the vocabulary, keeping the most frequent words, defaults to None.
+    pub max_vocabulary: Option<usize>,
+}
+
+impl Default for CoOccurrenceOptions {
+    fn default() -> Self {
+        Self {
+            window_size: 2,
+            distance_decay: DistanceDecay::Uniform,
+            directional: false,
+            window_boundary: WindowBoundary::Document,
+            min_count: 1,
+            max_vocabulary: None,
+        }
+    }
+}
+
+/// The parameters to build a co-occurrence matrix with an automatic vocabulary.
+pub enum CoOccurrenceParams<'a> {
+    /// Represents unprocessed documents to be analyzed.
+    ///
+    /// ## Arguments
+    /// * `documents`: The documents to be analyzed.
+    /// * `stop_words`: A list of stop words.
+    /// * `punctuation`: Optional list of punctuation symbols.
+    /// * `options`: The window and vocabulary options, see `CoOccurrenceOptions`.
+    UnprocessedDocuments(
+        Documents<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
+        CoOccurrenceOptions,
+    ),
+
+    /// Represents a text block to be analyzed.
+    ///
+    /// ## Arguments
+    /// * `text`: The text to be analyzed.
+    /// * `stop_words`: A list of stop words.
+    /// * `punctuation`: Optional list of punctuation symbols.
+    /// * `options`: The window and vocabulary options, see `CoOccurrenceOptions`.
+    TextBlock(
+        Text<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
+        CoOccurrenceOptions,
+    ),
+}
+
+pub(super) fn split_text(tokenizer: &Tokenizer, boundary: WindowBoundary) -> Vec<String> {
+    match boundary {
+        WindowBoundary::Document => vec![tokenizer.sync_split_into_words().join(" ")],
+        WindowBoundary::Sentences => tokenizer.sync_split_into_sentences(),
+        WindowBoundary::Paragraphs => tokenizer.sync_split_into_paragraphs(),
+    }
+}
+
+impl<'a> CoOccurrenceParams<'a> {
+    /// Returns the pre-processed documents, one per window boundary.
+    pub fn get_documents(&self) -> Vec<String> {
+        match self {
+            CoOccurrenceParams::UnprocessedDocuments(
+                documents,
+                stop_words,
+                punctuation,
+                options,
+            ) => {
+                #[cfg(feature = "parallel")]
+                {
+                    documents
+                        .par_iter()
+                        .flat_map_iter(|document| {
+                            split_text(
+                                &Tokenizer::new(document, stop_words, *punctuation),
+                                options.window_boundary,
+                            )
+                        })
+                        .collect::<Vec<String>>()
+                }
+
+                #[cfg(not(feature = "parallel"))]
+                {
+                    documents
+                        .iter()
+                        .flat_map(|document| {
+                            split_text(
+                                &Tokenizer::new(document, stop_words, *punctuation),
+                                options.window_boundary,
+                            )
+                        })
+                        .collect::<Vec<String>>()
+                }
+            }
+            CoOccurrenceParams::TextBlock(text, stop_words, punctuation, options) => split_text(
+                &Tokenizer::new(text, stop_words, *punctuation),
+                options.window_boundary,
+            ),
+        }
+    }
+
+    /// Returns the window and vocabulary options.
+    pub fn get_options(&self) -> CoOccurrenceOptions {
+        match self {
+            CoOccurrenceParams::UnprocessedDocuments(_, _, _, options) => *options,
+            CoOccurrenceParams::TextBlock(_, _, _, options) => *options,
+        }
+    }
+}
+
+/// The parameters to build a co-occurrence matrix between multi-word phrases.
+///
+/// The window size of the options counts phrases, not words.
+pub enum PhraseCoOccurrenceParams<'a> {
+    /// Represents a text block whose phrases are split on stop words and punctuation by the tokenizer.
+    ///
+    /// ## Arguments
+    /// * `text`: The text to be analyzed.
+    /// * `stop_words`: A list of stop words.
+    /// * `punctuation`: Optional list of punctuation symbols.
+    /// * `phrase_length`: Optional maximum number of words of a phrase.
+    /// * `options`: The window and vocabulary options, see `CoOccurrenceOptions`.
+    TokenizerPhrases(
+        Text<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
+        PhraseLength,
+        CoOccurrenceOptions,
+    ),
+
+    /// Represents a text block where the phrases of a list are tagged by longest match, other words are ignored.
+    ///
+    /// ## Arguments
+    /// * `text`: The text to be analyzed.
+    /// * `phrases`: The list of phrases to look for, stop words inside a phrase are kept.
+    /// * `punctuation`: Optional list of punctuation symbols.
+    /// * `options`: The window and vocabulary options, see `CoOccurrenceOptions`.
+    PhraseList(Text<'a>, Phrases<'a>, Punctuation<'a>, CoOccurrenceOptions),
+}
+
+fn split_phrases(
+    tokenizer: &Tokenizer,
+    boundary: WindowBoundary,
+    length: PhraseLength,
+) -> Vec<Vec<String>> {
+    match boundary {
+        WindowBoundary::Document => vec![tokenizer.sync_split_into_phrases(length)],
+        WindowBoundary::Sentences => tokenizer.sync_split_sentences_into_phrases(length),
+        WindowBoundary::Paragraphs => tokenizer.sync_split_paragraphs_into_phrases(length),
+    }
+}
+
+/// Replaces the words of a document by the longest phrases of the list they start, dropping the rest.
+fn tag_phrases(document: &str, phrases: &HashSet<String>, max_length: usize) -> Vec<String> {
+    let words = document.split_whitespace().collect::<Vec<&str>>();
+    let mut tagged = Vec::new();
+    let mut i = 0;
+
+    while i < words.len() {
+        let matched = (1..=max_length.min(words.len() - i))
+            .rev()
+            .map(|length| (length, words[i..i + length].join(" ")))
+            .find(|(_, phrase)| phrases.contains(phrase));
+
+        match matched {
+            Some((length, phrase)) => {
+                tagged.push(phrase);
+                i += length;
+            }
+            None => i += 1,
+        }
+    }
+
+    tagged
+}
+
+impl<'a> PhraseCoOccurrenceParams<'a> {
+    /// Returns the phrases of each document, one per window boundary.
+    pub fn get_phrase_documents(&self) -> Vec<Vec<String>> {
+        match self {
+            PhraseCoOccurrenceParams::TokenizerPhrases(
+                text,
+                stop_words,
+                punctuation,
+                phrase_length,
+                options,
+            ) => split_phrases(
+                &Tokenizer::new(text, stop_words, *punctuation),
+                options.window_boundary,
+                *phrase_length,
+            ),
+            PhraseCoOccurrenceParams::PhraseList(text, phrases, punctuation, options) => {
+                let phrases = phrases
+                    .iter()
+                    .map(|phrase| {
+                        Tokenizer::new(phrase, &[], *punctuation)
+                            .sync_split_into_words()
+                            .join(" ")
+                    })
+                    .filter(|phrase| !phrase.is_empty())
+                    .collect::<HashSet<String>>();
+                let max_length = phrases
+                    .iter()
+                    .map(|phrase| phrase.split(' ').count())
+                    .max()
+                    .unwrap_or(0);
+
+                split_text(
+                    &Tokenizer::new(text, &[], *punctuation),
+                    options.window_boundary,
+                )
+                .iter()
+                .map(|document| tag_phrases(document, &phrases, max_length))
+                .filter(|document| !document.is_empty())
+                .collect()
+            }
+        }
+    }
+
+    /// Returns the window and vocabulary options.
+    pub fn get_options(&self) -> CoOccurrenceOptions {
+        match self {
+            PhraseCoOccurrenceParams::TokenizerPhrases(_, _, _, _, options) => *options,
+            PhraseCoOccurrenceParams::PhraseList(_, _, _, options) => *options,
+        }
+    }
+}
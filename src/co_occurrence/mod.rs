@@ -0,0 +1,579 @@
+// Copyright (C) 2023 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::{collections::HashMap, ops::Range, sync::OnceLock};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+mod association;
+mod clustering;
+pub mod co_occurrence_builder;
+pub mod co_occurrence_params;
+mod sparse_matrix;
+mod word_vectors;
+pub use association::AssociationMeasure;
+pub use clustering::{ClusteringOptions, CommunityDetection, WordCluster};
+pub use co_occurrence_builder::CoOccurrenceBuilder;
+pub use co_occurrence_params::{
+    CoOccurrenceOptions, CoOccurrenceParams, DistanceDecay, PhraseCoOccurrenceParams,
+    WindowBoundary,
+};
+pub use sparse_matrix::SparseMatrix;
+pub use word_vectors::VectorWeighting;
+
+use association::AssociationCounts;
+use word_vectors::WordVectors;
+
+use crate::{
+    common::{Documents, WindowSize},
+    graph_export::{GraphEdge, GraphNode, WordGraph},
+};
+
+type Words<'a> = &'a [String];
+
+pub struct CoOccurrence {
+    matrix: SparseMatrix,
+    dense_matrix: OnceLock<Vec<Vec<f32>>>,
+    counts: SparseMatrix,
+    left_counts: Option<SparseMatrix>,
+    right_counts: Option<SparseMatrix>,
+    row_sums: Vec<f32>,
+    column_sums: Vec<f32>,
+    total: f32,
+    tokens: f32,
+    max: f32,
+    measure: AssociationMeasure,
+    vectors: WordVectors,
+    frequencies: Vec<usize>,
+    words: Vec<String>,
+    words_indexes: HashMap<String, usize>,
+}
+
+fn get_window_range(window_size: usize, index: usize, words_length: usize) -> Range<usize> {
+    let window_start = index.saturating_sub(window_size);
+    let window_end = index
+        .saturating_add(window_size)
+        .saturating_add(1)
+        .min(words_length);
+    window_start..window_end
+}
+
+fn create_words_indexes(words: &[String]) -> HashMap<String, usize> {
+    #[cfg(feature = "parallel")]
+    {
+        words
+            .par_iter()
+            .enumerate()
+            .map(|(i, w)| (w.to_string(), i))
+            .collect::<HashMap<String, usize>>()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (w.to_string(), i))
+            .collect::<HashMap<String, usize>>()
+    }
+}
+
+fn select_vocabulary<'a>(
+    frequencies: impl IntoIterator<Item = (&'a str, usize)>,
+    min_count: usize,
+    max_vocabulary: Option<usize>,
+) -> Vec<String> {
+    let mut vocabulary = frequencies
+        .into_iter()
+        .filter(|(_, count)| *count >= min_count)
+        .collect::<Vec<(&str, usize)>>();
+    vocabulary.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    if let Some(max_vocabulary) = max_vocabulary {
+        vocabulary.truncate(max_vocabulary);
+    }
+
+    vocabulary
+        .into_iter()
+        .map(|(word, _)| word.to_string())
+        .collect()
+}
+
+fn build_vocabulary(
+    documents: &[String],
+    min_count: usize,
+    max_vocabulary: Option<usize>,
+) -> Vec<String> {
+    let frequencies = documents
+        .iter()
+        .flat_map(|doc| doc.split_whitespace())
+        .fold(HashMap::<&str, usize>::new(), |mut acc, word| {
+            *acc.entry(word).or_insert(0) += 1;
+            acc
+        });
+    select_vocabulary(frequencies, min_count, max_vocabulary)
+}
+
+/// Gets the label of each word of the documents, None if the word is not in the vocabulary.
+fn label_documents<'a, D, T>(
+    documents: D,
+    words_indexes: &HashMap<String, usize>,
+) -> Vec<Vec<Option<usize>>>
+where
+    D: IntoIterator<Item = T>,
+    T: IntoIterator<Item = &'a str>,
+{
+    documents
+        .into_iter()
+        .map(|doc| {
+            doc.into_iter()
+                .map(|word| words_indexes.get(word).copied())
+                .collect::<Vec<Option<usize>>>()
+        })
+        .collect()
+}
+
+fn get_frequencies(documents: &[Vec<Option<usize>>], length: usize) -> Vec<usize> {
+    documents
+        .iter()
+        .flatten()
+        .flatten()
+        .fold(vec![0; length], |mut frequencies, index| {
+            frequencies[*index] += 1;
+            frequencies
+        })
+}
+
+/// Adds the co-occurrences of a document, given as the label of each word (None if not in the vocabulary).
+fn add_window_counts(
+    rows: &mut [HashMap<usize, f32>],
+    labels: &[Option<usize>],
+    window_size: usize,
+    distance_decay: DistanceDecay,
+    right_only: bool,
+) {
+    labels
+        .iter()
+        .enumerate()
+        .filter_map(|(i, label)| label.map(|first_index| (i, first_index)))
+        .for_each(|(i, first_index)| {
+            let window = get_window_range(window_size, i, labels.len());
+            let window = if right_only { i..window.end } else { window };
+            window
+                .filter_map(|j| {
+                    if i == j {
+                        return None;
+                    }
+
+                    labels[j].map(|other_index| (i.abs_diff(j), other_index))
+                })
+                .for_each(|(distance, other_index)| {
+                    *rows[first_index].entry(other_index).or_insert(0.0) +=
+                        distance_decay.weight(distance, window_size);
+                });
+        });
+}
+
+fn get_counts(
+    documents: &[Vec<Option<usize>>],
+    length: usize,
+    options: &CoOccurrenceOptions,
+    right_only: bool,
+) -> SparseMatrix {
+    let mut rows = vec![HashMap::<usize, f32>::new(); length];
+    documents.iter().for_each(|labels| {
+        add_window_counts(
+            &mut rows,
+            labels,
+            options.window_size,
+            options.distance_decay,
+            right_only,
+        );
+    });
+    SparseMatrix::from_rows(rows, length)
+}
+
+impl CoOccurrence {
+    /// Create a new CoOccurrence instance.
+    pub fn new(documents: Documents, words: Words, window_size: WindowSize) -> Self {
+        Self::with_options(
+            documents,
+            words,
+            &CoOccurrenceOptions {
+                window_size,
+                ..CoOccurrenceOptions::default()
+            },
+        )
+    }
+
+    /// Create a new CoOccurrence instance with distance decay and directional options.
+    ///
+    /// The vocabulary and window boundary options are ignored, as the documents and words are given.
+    pub fn with_options(documents: Documents, words: Words, options: &CoOccurrenceOptions) -> Self {
+        let words_indexes = create_words_indexes(words);
+        let labelled_documents = label_documents(
+            documents.iter().map(|doc| doc.split_whitespace()),
+            &words_indexes,
+        );
+        Self::from_labelled_documents(&labelled_documents, words.to_vec(), words_indexes, options)
+    }
+
+    /// Create a new CoOccurrence instance whose nodes are multi-word phrases instead of words.
+    pub fn from_phrases(params: PhraseCoOccurrenceParams) -> Self {
+        let options = params.get_options();
+        let documents = params.get_phrase_documents();
+        let frequencies =
+            documents
+                .iter()
+                .flatten()
+                .fold(HashMap::<&str, usize>::new(), |mut acc, phrase| {
+                    *acc.entry(phrase.as_str()).or_insert(0) += 1;
+                    acc
+                });
+        let phrases = select_vocabulary(frequencies, options.min_count, options.max_vocabulary);
+        let phrases_indexes = create_words_indexes(&phrases);
+        let labelled_documents = label_documents(
+            documents
+                .iter()
+                .map(|doc| doc.iter().map(|phrase| phrase.as_str())),
+            &phrases_indexes,
+        );
+        Self::from_labelled_documents(&labelled_documents, phrases, phrases_indexes, &options)
+    }
+
+    fn from_labelled_documents(
+        documents: &[Vec<Option<usize>>],
+        words: Vec<String>,
+        words_indexes: HashMap<String, usize>,
+        options: &CoOccurrenceOptions,
+    ) -> Self {
+        let length = words.len();
+        let right_counts = if options.directional {
+            Some(get_counts(documents, length, options, true))
+        } else {
+            None
+        };
+        Self::from_counts(
+            get_counts(documents, length, options, false),
+            right_counts,
+            get_frequencies(documents, length),
+            words,
+            words_indexes,
+        )
+    }
+
+    /// Create a new CoOccurrence instance from unprocessed text, deriving the vocabulary automatically.
+    pub fn from_params(params: CoOccurrenceParams) -> Self {
+        let options = params.get_options();
+        let documents = params.get_documents();
+        let words = build_vocabulary(&documents, options.min_count, options.max_vocabulary);
+        Self::with_options(&documents, &words, &options)
+    }
+
+    fn from_counts(
+        counts: SparseMatrix,
+        right_counts: Option<SparseMatrix>,
+        frequencies: Vec<usize>,
+        words: Vec<String>,
+        words_indexes: HashMap<String, usize>,
+    ) -> Self {
+        let max = counts.max();
+        let row_sums = counts.row_sums();
+
+        Self {
+            matrix: counts.map_values(|value| value / max),
+            dense_matrix: OnceLock::new(),
+            column_sums: counts.column_sums(),
+            vectors: WordVectors::from_counts(&counts),
+            total: row_sums.iter().sum::<f32>(),
+            tokens: frequencies.iter().sum::<usize>() as f32,
+            row_sums,
+            counts,
+            left_counts: right_counts.as_ref().map(|right| right.transpose()),
+            right_counts,
+            max,
+            measure: AssociationMeasure::Normalized,
+            frequencies,
+            words,
+            words_indexes,
+        }
+    }
+
+    /// Sets the association measure used by `get_association`, `get_associations` and `get_top_pairs`.
+    pub fn with_association_measure(mut self, measure: AssociationMeasure) -> Self {
+        self.measure = measure;
+        self
+    }
+
+    /// Sets the weighting of the word vectors used by `similarity` and `most_similar`,
+    /// optionally reducing them to the given number of dimensions with a truncated SVD.
+    pub fn with_word_vectors(
+        mut self,
+        weighting: VectorWeighting,
+        dimensions: Option<usize>,
+    ) -> Self {
+        self.vectors = WordVectors::new(
+            &self.counts,
+            &self.row_sums,
+            &self.column_sums,
+            self.total,
+            weighting,
+            dimensions,
+        );
+        self
+    }
+
+    /// Get the numeric label of a word.
+    pub fn get_label(&self, word: &str) -> Option<usize> {
+        self.words_indexes.get(word).map(|w| w.to_owned())
+    }
+
+    /// Get the word of a numeric label.
+    pub fn get_word(&self, label: usize) -> Option<String> {
+        self.words.get(label).map(|w| w.to_owned())
+    }
+
+    /// Get the dense matrix of the co-occurrence, built on the first call and kept, this allocates words × words values.
+    #[deprecated(
+        since = "1.6.0",
+        note = "use `get_sparse_matrix`, or `get_dense_matrix` for a dense copy"
+    )]
+    pub fn get_matrix(&self) -> &Vec<Vec<f32>> {
+        self.dense_matrix.get_or_init(|| self.matrix.to_dense())
+    }
+
+    /// Get the sparse matrix of the co-occurrence.
+    pub fn get_sparse_matrix(&self) -> &SparseMatrix {
+        &self.matrix
+    }
+
+    /// Get a dense copy of the matrix of the co-occurrence, this allocates words × words values.
+    pub fn get_dense_matrix(&self) -> Vec<Vec<f32>> {
+        self.matrix.to_dense()
+    }
+
+    /// Get the labels of the co-occurrence.
+    pub fn get_labels(&self) -> &HashMap<String, usize> {
+        &self.words_indexes
+    }
+
+    /// Get all relations of a given word.
+    pub fn get_relations(&self, word: &str) -> Option<Vec<(String, f32)>> {
+        let label = self.get_label(word)?;
+
+        Some(
+            self.matrix
+                .row(label)
+                .filter_map(|(i, v)| self.get_word(i).map(|w| (w, v)))
+                .collect::<Vec<(String, f32)>>(),
+        )
+    }
+
+    /// Get the row of a given word.
+    pub fn get_matrix_row(&self, word: &str) -> Option<Vec<f32>> {
+        let label = self.get_label(word)?;
+        Some(self.matrix.to_dense_row(label))
+    }
+
+    /// Get the relation between two words.
+    pub fn get_relation(&self, word1: &str, word2: &str) -> Option<f32> {
+        let label1 = self.get_label(word1)?;
+        let label2 = self.get_label(word2)?;
+        Some(self.matrix.get(label1, label2))
+    }
+
+    /// Get the sparse matrix of the raw co-occurrence counts.
+    pub fn get_count_matrix(&self) -> &SparseMatrix {
+        &self.counts
+    }
+
+    /// Get the sparse matrix of the left context counts, where row x and column y counts y appearing before x.
+    ///
+    /// Only available when built with the `directional` option.
+    pub fn get_left_context_matrix(&self) -> Option<&SparseMatrix> {
+        self.left_counts.as_ref()
+    }
+
+    /// Get the sparse matrix of the right context counts, where row x and column y counts y appearing after x.
+    ///
+    /// Only available when built with the `directional` option.
+    pub fn get_right_context_matrix(&self) -> Option<&SparseMatrix> {
+        self.right_counts.as_ref()
+    }
+
+    /// Get the count of the second word appearing after the first one (first → second).
+    ///
+    /// Only available when built with the `directional` option.
+    pub fn get_directed_relation(&self, first: &str, second: &str) -> Option<f32> {
+        let label1 = self.get_label(first)?;
+        let label2 = self.get_label(second)?;
+        Some(self.right_counts.as_ref()?.get(label1, label2))
+    }
+
+    fn score_association(&self, label1: usize, label2: usize, pair: f32) -> f32 {
+        self.measure.score(&AssociationCounts {
+            pair,
+            word1: self.row_sums[label1],
+            word2: self.column_sums[label2],
+            total: self.total,
+            frequency1: self.frequencies[label1] as f32,
+            frequency2: self.frequencies[label2] as f32,
+            tokens: self.tokens,
+            max: self.max,
+        })
+    }
+
+    /// Get the association between two words with the selected association measure.
+    pub fn get_association(&self, word1: &str, word2: &str) -> Option<f32> {
+        let label1 = self.get_label(word1)?;
+        let label2 = self.get_label(word2)?;
+        Some(self.score_association(label1, label2, self.counts.get(label1, label2)))
+    }
+
+    /// Get the associations of a given word with every word it co-occurs with, sorted by score.
+    pub fn get_associations(&self, word: &str) -> Option<Vec<(String, f32)>> {
+        let label = self.get_label(word)?;
+        let mut associations = self
+            .counts
+            .row(label)
+            .filter_map(|(i, pair)| {
+                self.get_word(i)
+                    .map(|w| (w, self.score_association(label, i, pair)))
+            })
+            .collect::<Vec<(String, f32)>>();
+        associations.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Some(associations)
+    }
+
+    /// Get the top n most associated pairs of different words across the whole vocabulary.
+    pub fn get_top_pairs(&self, n: usize) -> Vec<(String, String, f32)> {
+        #[cfg(feature = "parallel")]
+        let mut pairs = (0..self.counts.size())
+            .into_par_iter()
+            .flat_map_iter(|row| {
+                self.counts
+                    .row(row)
+                    .filter(move |(column, _)| row < *column)
+                    .map(move |(column, pair)| {
+                        (row, column, self.score_association(row, column, pair))
+                    })
+            })
+            .collect::<Vec<(usize, usize, f32)>>();
+
+        #[cfg(not(feature = "parallel"))]
+        let mut pairs = self
+            .counts
+            .iter()
+            .filter(|(row, column, _)| row < column)
+            .map(|(row, column, pair)| (row, column, self.score_association(row, column, pair)))
+            .collect::<Vec<(usize, usize, f32)>>();
+
+        pairs.sort_by(|a, b| {
+            b.2.total_cmp(&a.2)
+                .then_with(|| self.words[a.0].cmp(&self.words[b.0]))
+                .then_with(|| self.words[a.1].cmp(&self.words[b.1]))
+        });
+        pairs
+            .into_iter()
+            .take(n)
+            .map(|(row, column, score)| {
+                (
+                    self.words[row].to_string(),
+                    self.words[column].to_string(),
+                    score,
+                )
+            })
+            .collect()
+    }
+
+    /// Get the vector of a word, its row of the (weighted and reduced) co-occurrence matrix.
+    pub fn get_word_vector(&self, word: &str) -> Option<Vec<f32>> {
+        let label = self.get_label(word)?;
+        Some(self.vectors.get_vector(&self.counts, label))
+    }
+
+    /// Get the non-zero values of the vector of a word as (dimension, value) pairs, sorted by dimension.
+    pub fn get_sparse_word_vector(&self, word: &str) -> Option<Vec<(usize, f32)>> {
+        let label = self.get_label(word)?;
+        Some(self.vectors.get_sparse_vector(&self.counts, label))
+    }
+
+    /// Get the cosine similarity between the vectors of two words.
+    pub fn similarity(&self, word1: &str, word2: &str) -> Option<f32> {
+        let label1 = self.get_label(word1)?;
+        let label2 = self.get_label(word2)?;
+        Some(self.vectors.cosine(&self.counts, label1, label2))
+    }
+
+    /// Get the k words with the most similar vectors to a given word, sorted by cosine similarity.
+    pub fn most_similar(&self, word: &str, k: usize) -> Option<Vec<(String, f32)>> {
+        let label = self.get_label(word)?;
+        let mut similar = self.vectors.cosine_all(&self.counts, label);
+        similar.sort_by(|a, b| {
+            b.1.total_cmp(&a.1)
+                .then_with(|| self.words[a.0].cmp(&self.words[b.0]))
+        });
+        Some(
+            similar
+                .into_iter()
+                .take(k)
+                .map(|(i, score)| (self.words[i].to_string(), score))
+                .collect(),
+        )
+    }
+
+    /// Groups the words into clusters with community detection on the co-occurrence graph.
+    ///
+    /// Words that do not co-occur with any other word are left out.
+    pub fn get_clusters(&self, options: &ClusteringOptions) -> Vec<WordCluster> {
+        let graph = (0..self.counts.size())
+            .map(|row| {
+                self.counts
+                    .row(row)
+                    .filter(|(column, _)| *column != row)
+                    .collect::<Vec<(usize, f32)>>()
+            })
+            .collect::<Vec<Vec<(usize, f32)>>>();
+        clustering::get_word_clusters(&graph, &self.words, options)
+    }
+
+    /// Get the co-occurrence graph, with the edges whose normalised weight is below the threshold pruned.
+    ///
+    /// Nodes carry the weighted degree as score and the word frequency, each edge is only given once.
+    pub fn get_graph(&self, threshold: f32) -> WordGraph {
+        let nodes = self
+            .words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| GraphNode {
+                id: word.to_string(),
+                score: self.row_sums[i],
+                frequency: self.frequencies[i],
+            })
+            .collect::<Vec<GraphNode>>();
+        let edges = self
+            .matrix
+            .iter()
+            .filter(|(row, column, _)| row <= column)
+            .map(|(row, column, weight)| GraphEdge {
+                source: self.words[row].to_string(),
+                target: self.words[column].to_string(),
+                weight,
+            })
+            .collect::<Vec<GraphEdge>>();
+        WordGraph::new(false, nodes, edges).prune(threshold)
+    }
+}
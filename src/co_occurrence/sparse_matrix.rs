@@ -0,0 +1,246 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Square sparse matrix in compressed sparse row format, only the non-zero values are stored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SparseMatrix {
+    size: usize,
+    offsets: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<f32>,
+}
+
+impl SparseMatrix {
+    /// Compiles a list of hashed rows into a sparse matrix with sorted columns.
+    pub fn from_rows(rows: Vec<HashMap<usize, f32>>, size: usize) -> Self {
+        let capacity = rows.iter().map(|row| row.len()).sum::<usize>();
+        let mut offsets = Vec::with_capacity(size + 1);
+        let mut columns = Vec::with_capacity(capacity);
+        let mut values = Vec::with_capacity(capacity);
+        offsets.push(0);
+
+        rows.into_iter()
+            .chain(std::iter::repeat_with(HashMap::new))
+            .take(size)
+            .for_each(|row| {
+                let mut entries = row
+                    .into_iter()
+                    .filter(|(_, value)| *value != 0.0)
+                    .collect::<Vec<(usize, f32)>>();
+                entries.sort_unstable_by_key(|(column, _)| *column);
+                entries.into_iter().for_each(|(column, value)| {
+                    columns.push(column);
+                    values.push(value);
+                });
+                offsets.push(columns.len());
+            });
+
+        Self {
+            size,
+            offsets,
+            columns,
+            values,
+        }
+    }
+
+    /// Number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Number of non-zero values stored in the matrix.
+    pub fn non_zero_count(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Gets the value of a cell, zero if the cell is not stored.
+    pub fn get(&self, row: usize, column: usize) -> f32 {
+        if row >= self.size {
+            return 0.0;
+        }
+
+        let start = self.offsets[row];
+        let end = self.offsets[row + 1];
+        match self.columns[start..end].binary_search(&column) {
+            Ok(i) => self.values[start + i],
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Iterates over the non-zero cells of a row, sorted by column.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, f32)> + '_ {
+        let (start, end) = if row < self.size {
+            (self.offsets[row], self.offsets[row + 1])
+        } else {
+            (0, 0)
+        };
+        self.columns[start..end]
+            .iter()
+            .copied()
+            .zip(self.values[start..end].iter().copied())
+    }
+
+    /// Iterates over all the non-zero cells as (row, column, value).
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, f32)> + '_ {
+        (0..self.size).flat_map(move |row| {
+            self.row(row)
+                .map(move |(column, value)| (row, column, value))
+        })
+    }
+
+    /// Gets the largest value stored in the matrix.
+    pub fn max(&self) -> f32 {
+        #[cfg(feature = "parallel")]
+        {
+            self.values.par_iter().copied().reduce(|| 0.0_f32, f32::max)
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            self.values.iter().copied().fold(0.0_f32, f32::max)
+        }
+    }
+
+    /// Gets a dense copy of a row.
+    pub fn to_dense_row(&self, row: usize) -> Vec<f32> {
+        let mut dense_row = vec![0.0_f32; self.size];
+        self.row(row)
+            .for_each(|(column, value)| dense_row[column] = value);
+        dense_row
+    }
+
+    /// Gets a dense copy of the whole matrix, this allocates size × size values.
+    pub fn to_dense(&self) -> Vec<Vec<f32>> {
+        #[cfg(feature = "parallel")]
+        {
+            (0..self.size)
+                .into_par_iter()
+                .map(|row| self.to_dense_row(row))
+                .collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            (0..self.size).map(|row| self.to_dense_row(row)).collect()
+        }
+    }
+
+    /// Creates a new matrix with the same non-zero cells and each value mapped.
+    pub fn map_values<F: Fn(f32) -> f32 + Sync + Send>(&self, f: F) -> Self {
+        #[cfg(feature = "parallel")]
+        let values = self.values.par_iter().map(|v| f(*v)).collect::<Vec<f32>>();
+
+        #[cfg(not(feature = "parallel"))]
+        let values = self.values.iter().map(|v| f(*v)).collect::<Vec<f32>>();
+
+        Self {
+            size: self.size,
+            offsets: self.offsets.to_vec(),
+            columns: self.columns.to_vec(),
+            values,
+        }
+    }
+
+    /// Creates a new matrix with the same non-zero cells and each value mapped from (row, column, value).
+    pub fn map_cells<F: Fn(usize, usize, f32) -> f32 + Sync + Send>(&self, f: F) -> Self {
+        #[cfg(feature = "parallel")]
+        let values = (0..self.size)
+            .into_par_iter()
+            .flat_map_iter(|row| {
+                self.row(row)
+                    .map(|(column, value)| f(row, column, value))
+                    .collect::<Vec<f32>>()
+            })
+            .collect::<Vec<f32>>();
+
+        #[cfg(not(feature = "parallel"))]
+        let values = self
+            .iter()
+            .map(|(row, column, value)| f(row, column, value))
+            .collect::<Vec<f32>>();
+
+        Self {
+            size: self.size,
+            offsets: self.offsets.to_vec(),
+            columns: self.columns.to_vec(),
+            values,
+        }
+    }
+
+    /// Multiplies the matrix by a dense vector.
+    pub fn mul_vector(&self, vector: &[f32]) -> Vec<f32> {
+        let row_dot = |row: usize| {
+            self.row(row)
+                .map(|(column, value)| value * vector[column])
+                .sum::<f32>()
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            (0..self.size).into_par_iter().map(row_dot).collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            (0..self.size).map(row_dot).collect()
+        }
+    }
+
+    /// Multiplies the transposed matrix by a dense vector.
+    pub fn transpose_mul_vector(&self, vector: &[f32]) -> Vec<f32> {
+        self.iter().fold(
+            vec![0.0_f32; self.size],
+            |mut result, (row, column, value)| {
+                result[column] += value * vector[row];
+                result
+            },
+        )
+    }
+
+    /// Creates the transposed matrix, where each cell (row, column) becomes (column, row).
+    pub fn transpose(&self) -> Self {
+        let rows = self.iter().fold(
+            vec![HashMap::<usize, f32>::new(); self.size],
+            |mut rows, (row, column, value)| {
+                rows[column].insert(row, value);
+                rows
+            },
+        );
+        Self::from_rows(rows, self.size)
+    }
+
+    /// Gets the sum of each row.
+    pub fn row_sums(&self) -> Vec<f32> {
+        (0..self.size)
+            .map(|row| self.row(row).map(|(_, value)| value).sum::<f32>())
+            .collect()
+    }
+
+    /// Gets the sum of each column.
+    pub fn column_sums(&self) -> Vec<f32> {
+        self.columns.iter().zip(self.values.iter()).fold(
+            vec![0.0_f32; self.size],
+            |mut sums, (column, value)| {
+                sums[*column] += value;
+                sums
+            },
+        )
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::common::next_random;
+
+use super::{
+    association::{AssociationCounts, AssociationMeasure},
+    sparse_matrix::SparseMatrix,
+};
+
+const SVD_ITERATIONS: usize = 30;
+const SVD_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The weighting of the co-occurrence counts used as word vectors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorWeighting {
+    /// The raw co-occurrence counts.
+    Counts,
+
+    /// Positive pointwise mutual information of each cell.
+    Ppmi,
+}
+
+enum Vectors {
+    Counts,
+    Sparse(SparseMatrix),
+    Dense(Vec<Vec<f32>>),
+}
+
+/// The word vectors of a co-occurrence matrix, one per row.
+pub(super) struct WordVectors {
+    vectors: Vectors,
+    norms: Vec<f32>,
+}
+
+fn sparse_norms(matrix: &SparseMatrix) -> Vec<f32> {
+    matrix
+        .map_values(|value| value * value)
+        .row_sums()
+        .into_iter()
+        .map(f32::sqrt)
+        .collect()
+}
+
+fn sparse_dot(matrix: &SparseMatrix, row1: usize, row2: usize) -> f32 {
+    let mut other = matrix.row(row2).peekable();
+
+    matrix.row(row1).fold(0.0_f32, |dot, (column, value)| {
+        while other.next_if(|(c, _)| *c < column).is_some() {}
+
+        match other.peek() {
+            Some((c, other_value)) if *c == column => dot + value * other_value,
+            _ => dot,
+        }
+    })
+}
+
+fn dense_dot(vector1: &[f32], vector2: &[f32]) -> f32 {
+    vector1.iter().zip(vector2.iter()).map(|(a, b)| a * b).sum()
+}
+
+fn get_ppmi_matrix(
+    counts: &SparseMatrix,
+    row_sums: &[f32],
+    column_sums: &[f32],
+    total: f32,
+) -> SparseMatrix {
+    counts.map_cells(|row, column, pair| {
+        AssociationMeasure::Ppmi.score(&AssociationCounts {
+            pair,
+            word1: row_sums[row],
+            word2: column_sums[column],
+            total,
+            frequency1: 0.0,
+            frequency2: 0.0,
+            tokens: 0.0,
+            max: 0.0,
+        })
+    })
+}
+
+fn get_random_vector(size: usize, state: &mut u64) -> Vec<f32> {
+    (0..size)
+        .map(|_| (next_random(state) >> 40) as f32 / (1u64 << 23) as f32 - 1.0)
+        .collect()
+}
+
+fn orthonormalize(vectors: &mut [Vec<f32>]) {
+    (0..vectors.len()).for_each(|j| {
+        let (previous, rest) = vectors.split_at_mut(j);
+        let vector = &mut rest[0];
+
+        previous.iter().for_each(|other| {
+            let projection = dense_dot(vector, other);
+            vector
+                .iter_mut()
+                .zip(other.iter())
+                .for_each(|(v, o)| *v -= projection * o);
+        });
+
+        let norm = dense_dot(vector, vector).sqrt();
+        vector.iter_mut().for_each(|v| {
+            *v = if norm > f32::EPSILON { *v / norm } else { 0.0 };
+        });
+    });
+}
+
+/// Truncated SVD by subspace iteration, returns the rows projected onto the top right singular vectors (U Σ).
+fn truncated_svd(matrix: &SparseMatrix, dimensions: usize) -> Vec<Vec<f32>> {
+    let size = matrix.size();
+    let mut state = SVD_SEED;
+    let mut basis = (0..dimensions.min(size))
+        .map(|_| get_random_vector(size, &mut state))
+        .collect::<Vec<Vec<f32>>>();
+    orthonormalize(&mut basis);
+
+    (0..SVD_ITERATIONS).for_each(|_| {
+        basis = basis
+            .iter()
+            .map(|vector| matrix.transpose_mul_vector(&matrix.mul_vector(vector)))
+            .collect();
+        orthonormalize(&mut basis);
+    });
+
+    let projections = basis
+        .iter()
+        .map(|vector| matrix.mul_vector(vector))
+        .collect::<Vec<Vec<f32>>>();
+
+    (0..size)
+        .map(|i| projections.iter().map(|projection| projection[i]).collect())
+        .collect()
+}
+
+impl WordVectors {
+    /// Uses the rows of the count matrix as word vectors.
+    pub(super) fn from_counts(counts: &SparseMatrix) -> Self {
+        Self {
+            vectors: Vectors::Counts,
+            norms: sparse_norms(counts),
+        }
+    }
+
+    /// Weights the count matrix and optionally reduces it to the given number of dimensions.
+    pub(super) fn new(
+        counts: &SparseMatrix,
+        row_sums: &[f32],
+        column_sums: &[f32],
+        total: f32,
+        weighting: VectorWeighting,
+        dimensions: Option<usize>,
+    ) -> Self {
+        let weighted = match weighting {
+            VectorWeighting::Counts => None,
+            VectorWeighting::Ppmi => Some(get_ppmi_matrix(counts, row_sums, column_sums, total)),
+        };
+
+        match (dimensions, weighted) {
+            (Some(dimensions), weighted) => {
+                let dense = truncated_svd(weighted.as_ref().unwrap_or(counts), dimensions);
+                Self {
+                    norms: dense
+                        .iter()
+                        .map(|vector| dense_dot(vector, vector).sqrt())
+                        .collect(),
+                    vectors: Vectors::Dense(dense),
+                }
+            }
+            (None, Some(weighted)) => Self {
+                norms: sparse_norms(&weighted),
+                vectors: Vectors::Sparse(weighted),
+            },
+            (None, None) => Self::from_counts(counts),
+        }
+    }
+
+    /// Gets the dense vector of a row.
+    pub(super) fn get_vector(&self, counts: &SparseMatrix, label: usize) -> Vec<f32> {
+        match &self.vectors {
+            Vectors::Counts => counts.to_dense_row(label),
+            Vectors::Sparse(matrix) => matrix.to_dense_row(label),
+            Vectors::Dense(vectors) => vectors[label].to_vec(),
+        }
+    }
+
+    /// Gets the non-zero values of a row, sorted by column.
+    pub(super) fn get_sparse_vector(
+        &self,
+        counts: &SparseMatrix,
+        label: usize,
+    ) -> Vec<(usize, f32)> {
+        match &self.vectors {
+            Vectors::Counts => counts.row(label).collect(),
+            Vectors::Sparse(matrix) => matrix.row(label).collect(),
+            Vectors::Dense(vectors) => vectors[label]
+                .iter()
+                .copied()
+                .enumerate()
+                .filter(|(_, value)| *value != 0.0)
+                .collect(),
+        }
+    }
+
+    /// Cosine similarity between two rows, zero if either row is empty.
+    pub(super) fn cosine(&self, counts: &SparseMatrix, label1: usize, label2: usize) -> f32 {
+        let norms = self.norms[label1] * self.norms[label2];
+
+        if norms <= 0.0 {
+            return 0.0;
+        }
+
+        let dot = match &self.vectors {
+            Vectors::Counts => sparse_dot(counts, label1, label2),
+            Vectors::Sparse(matrix) => sparse_dot(matrix, label1, label2),
+            Vectors::Dense(vectors) => dense_dot(&vectors[label1], &vectors[label2]),
+        };
+        (dot / norms).clamp(-1.0, 1.0)
+    }
+
+    /// Cosine similarity between a row and every other row with a non-zero vector.
+    pub(super) fn cosine_all(&self, counts: &SparseMatrix, label: usize) -> Vec<(usize, f32)> {
+        let size = self.norms.len();
+        let score = |other: usize| {
+            if other == label || self.norms[other] <= 0.0 {
+                return None;
+            }
+
+            Some((other, self.cosine(counts, label, other)))
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            (0..size).into_par_iter().filter_map(score).collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            (0..size).filter_map(score).collect()
+        }
+    }
+}
@@ -106,6 +106,24 @@ pub fn get_ranked_scores(map: &HashMap<String, f32, RandomState>, n: usize) -> V
     }
 }
 
+/// Gets the keywords whose score is at least the threshold, sorted by score.
+#[cfg(any(
+    feature = "tf_idf",
+    feature = "rake",
+    feature = "text_rank",
+    feature = "yake"
+))]
+pub fn get_scores_above(
+    map: &HashMap<String, f32, RandomState>,
+    threshold: f32,
+) -> Vec<(String, f32)> {
+    sort_ranked_map(map)
+        .into_iter()
+        .take_while(|(_, score)| **score >= threshold)
+        .map(|(word, score)| (word.to_string(), *score))
+        .collect()
+}
+
 pub fn get_special_char_regex() -> Option<Regex> {
     Regex::new(r"('s|,|\.|\s)").ok()
 }
@@ -132,7 +150,11 @@ pub fn process_word(
     Some(word)
 }
 
-#[cfg(feature = "yake")]
-pub fn get_space_regex() -> Option<Regex> {
-    Regex::new(r"[\n\t\r]").ok()
+/// Xorshift64 pseudo-random generator, deterministic for a given (non-zero) state.
+#[cfg(feature = "co_occurrence")]
+pub fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
 }
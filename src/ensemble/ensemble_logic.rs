@@ -0,0 +1,228 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::common::{sort_ranked_map, PhraseLength, Punctuation, Stopwords, Text};
+#[cfg(feature = "rake")]
+use crate::rake::{Rake, RakeParams};
+#[cfg(feature = "text_rank")]
+use crate::text_rank::{TextRank, TextRankParams};
+#[cfg(feature = "tf_idf")]
+use crate::tf_idf::{TextSplit, TfIdf, TfIdfParams};
+#[cfg(feature = "yake")]
+use crate::yake::{Yake, YakeParams};
+
+use super::ensemble_params::{EnsembleAlgorithm, EnsembleOptions, RankFusion};
+
+pub type AlgorithmRanks = HashMap<EnsembleAlgorithm, HashMap<String, f32>>;
+
+/// Lowercases the words and trims the punctuation around them, so keyphrases match across algorithms.
+pub fn normalise_keyword(keyword: &str) -> String {
+    keyword
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Keys the scores by normalised form, keeping the highest score of the keywords that share a form.
+fn normalise_scores(scores: &HashMap<String, f32>) -> HashMap<String, f32> {
+    scores
+        .iter()
+        .fold(HashMap::new(), |mut acc, (keyword, score)| {
+            let keyword = normalise_keyword(keyword);
+
+            if !keyword.is_empty() {
+                let entry = acc.entry(keyword).or_insert(*score);
+                *entry = entry.max(*score);
+            }
+
+            acc
+        })
+}
+
+fn run_algorithm(
+    algorithm: EnsembleAlgorithm,
+    text: Text,
+    stop_words: Stopwords,
+    punctuation: Punctuation,
+    phrase_length: PhraseLength,
+    options: EnsembleOptions,
+) -> HashMap<String, f32> {
+    #[cfg(not(any(feature = "rake", feature = "text_rank", feature = "yake")))]
+    let _ = phrase_length;
+    #[cfg(not(any(feature = "text_rank", feature = "yake")))]
+    let _ = options;
+
+    match algorithm {
+        #[cfg(feature = "rake")]
+        EnsembleAlgorithm::Rake => normalise_scores(
+            Rake::new(RakeParams::All(
+                text,
+                stop_words,
+                punctuation,
+                phrase_length,
+            ))
+            .get_phrase_scores_map(),
+        ),
+        #[cfg(feature = "text_rank")]
+        EnsembleAlgorithm::TextRank => normalise_scores(
+            TextRank::new(TextRankParams::WithOptions(
+                text,
+                stop_words,
+                punctuation,
+                phrase_length,
+                options.text_rank,
+            ))
+            .get_phrase_scores_map(),
+        ),
+        #[cfg(feature = "tf_idf")]
+        EnsembleAlgorithm::TfIdf => normalise_scores(
+            TfIdf::new(TfIdfParams::TextBlock(
+                text,
+                stop_words,
+                punctuation,
+                TextSplit::Sentences,
+            ))
+            .get_word_scores_map(),
+        ),
+        #[cfg(feature = "yake")]
+        EnsembleAlgorithm::Yake => normalise_scores(
+            Yake::new(YakeParams::All(
+                text,
+                stop_words,
+                punctuation,
+                options.yake_threshold,
+                options
+                    .yake_ngram
+                    .unwrap_or_else(|| phrase_length.unwrap_or(3)),
+                options.yake_window_size,
+            ))
+            .get_keyword_scores_map(),
+        ),
+    }
+}
+
+/// The contribution of each keyword of a ranking to the fused score, before its weight.
+fn get_fusion_scores(scores: &HashMap<String, f32>, fusion: RankFusion) -> Vec<(&String, f32)> {
+    let ranked = sort_ranked_map(scores);
+    let length = ranked.len() as f32;
+
+    match fusion {
+        RankFusion::ReciprocalRank(k) => ranked
+            .into_iter()
+            .enumerate()
+            .map(|(i, (keyword, _))| (keyword, 1.0 / (k + i as f32 + 1.0)))
+            .collect(),
+        RankFusion::Borda => ranked
+            .into_iter()
+            .enumerate()
+            .map(|(i, (keyword, _))| (keyword, (length - i as f32) / length))
+            .collect(),
+        RankFusion::WeightedAverage => {
+            let (min, max) = ranked.iter().fold(
+                (f32::INFINITY, f32::NEG_INFINITY),
+                |(min, max), (_, score)| (min.min(**score), max.max(**score)),
+            );
+            let range = max - min;
+
+            ranked
+                .into_iter()
+                .map(|(keyword, score)| {
+                    (
+                        keyword,
+                        if range > 0.0 {
+                            (score - min) / range
+                        } else {
+                            1.0
+                        },
+                    )
+                })
+                .collect()
+        }
+    }
+}
+
+pub struct EnsembleLogic;
+
+impl EnsembleLogic {
+    pub fn build_ensemble(
+        text: Text,
+        stop_words: Stopwords,
+        punctuation: Punctuation,
+        phrase_length: PhraseLength,
+        weights: &[(EnsembleAlgorithm, f32)],
+        fusion: RankFusion,
+        options: EnsembleOptions,
+    ) -> (HashMap<String, f32>, AlgorithmRanks) {
+        // Kept in the order of the weights, so the fused scores are summed in the same order on every run.
+        let mut weights = weights.iter().fold(
+            Vec::<(EnsembleAlgorithm, f32)>::new(),
+            |mut acc, (algorithm, weight)| {
+                match acc.iter_mut().find(|(other, _)| other == algorithm) {
+                    Some((_, total)) => *total += weight,
+                    None => acc.push((*algorithm, *weight)),
+                }
+                acc
+            },
+        );
+        // An algorithm without weight cannot change the fused scores, so it is not run.
+        weights.retain(|(_, weight)| *weight != 0.0);
+        let algorithm_ranks = weights
+            .iter()
+            .map(|(algorithm, _)| {
+                (
+                    *algorithm,
+                    run_algorithm(
+                        *algorithm,
+                        text,
+                        stop_words,
+                        punctuation,
+                        phrase_length,
+                        options,
+                    ),
+                )
+            })
+            .collect::<AlgorithmRanks>();
+        let mut keyword_rank = weights.iter().fold(
+            BTreeMap::<String, f32>::new(),
+            |mut acc, (algorithm, weight)| {
+                get_fusion_scores(&algorithm_ranks[algorithm], fusion)
+                    .into_iter()
+                    .for_each(|(keyword, score)| {
+                        *acc.entry(keyword.to_string()).or_insert(0.0) += weight * score;
+                    });
+                acc
+            },
+        );
+
+        if fusion == RankFusion::WeightedAverage {
+            let total_weight = weights.iter().map(|(_, weight)| weight).sum::<f32>();
+
+            if total_weight > 0.0 {
+                keyword_rank
+                    .values_mut()
+                    .for_each(|score| *score /= total_weight);
+            }
+        }
+
+        (keyword_rank.into_iter().collect(), algorithm_ranks)
+    }
+}
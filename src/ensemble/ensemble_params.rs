@@ -0,0 +1,232 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+#[cfg(feature = "yake")]
+use crate::common::WindowSize;
+use crate::common::{PhraseLength, Punctuation, Stopwords, Text};
+#[cfg(feature = "text_rank")]
+use crate::text_rank::TextRankOptions;
+
+/// An algorithm that can take part in the ensemble.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EnsembleAlgorithm {
+    /// RAKE phrase scores.
+    #[cfg(feature = "rake")]
+    Rake,
+
+    /// TextRank phrase scores.
+    #[cfg(feature = "text_rank")]
+    TextRank,
+
+    /// TF-IDF word scores, with the sentences of the text as documents.
+    ///
+    /// Only single words are scored, so it adds nothing to the fused score of multi-word phrases.
+    #[cfg(feature = "tf_idf")]
+    TfIdf,
+
+    /// YAKE keyword scores.
+    #[cfg(feature = "yake")]
+    Yake,
+}
+
+/// How the rankings of the algorithms are fused into a single ranking.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RankFusion {
+    /// Reciprocal rank fusion, the weighted sum of `1 / (k + rank)`, with the given `k` (usually 60).
+    ReciprocalRank(f32),
+
+    /// Borda count, the weighted sum of the positions from the bottom of each ranking, normalised by its length.
+    Borda,
+
+    /// Weighted average of the min-max normalised scores, a keyword missing from a ranking scores 0 in it.
+    WeightedAverage,
+}
+
+impl Default for RankFusion {
+    fn default() -> Self {
+        Self::ReciprocalRank(60.0)
+    }
+}
+
+/// The settings of the algorithms run by the ensemble.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnsembleOptions {
+    /// The TextRank graph and power iteration options, defaults to `TextRankOptions::default()`.
+    #[cfg(feature = "text_rank")]
+    pub text_rank: TextRankOptions,
+    /// The YAKE deduplication threshold, defaults to 0.85.
+    #[cfg(feature = "yake")]
+    pub yake_threshold: f32,
+    /// The YAKE context window size, defaults to 2.
+    #[cfg(feature = "yake")]
+    pub yake_window_size: WindowSize,
+    /// The YAKE n-gram size, defaults to the phrase length, or 3 when that is unset.
+    #[cfg(feature = "yake")]
+    pub yake_ngram: Option<usize>,
+}
+
+// Only derivable when the yake feature is off.
+#[allow(clippy::derivable_impls)]
+impl Default for EnsembleOptions {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "text_rank")]
+            text_rank: TextRankOptions::default(),
+            #[cfg(feature = "yake")]
+            yake_threshold: 0.85,
+            #[cfg(feature = "yake")]
+            yake_window_size: 2,
+            #[cfg(feature = "yake")]
+            yake_ngram: None,
+        }
+    }
+}
+
+type Weights<'a> = &'a [(EnsembleAlgorithm, f32)];
+
+/// Every enabled algorithm, with the same weight.
+const DEFAULT_WEIGHTS: &[(EnsembleAlgorithm, f32)] = &[
+    #[cfg(feature = "rake")]
+    (EnsembleAlgorithm::Rake, 1.0),
+    #[cfg(feature = "text_rank")]
+    (EnsembleAlgorithm::TextRank, 1.0),
+    #[cfg(feature = "tf_idf")]
+    (EnsembleAlgorithm::TfIdf, 1.0),
+    #[cfg(feature = "yake")]
+    (EnsembleAlgorithm::Yake, 1.0),
+];
+
+/// The parameters to be used in the ensemble keyword extractor.
+pub enum EnsembleParams<'a> {
+    /// ## Arguments
+    /// 1. `text` - The text to be analyzed.
+    /// 2. `stop_words` - A list of stop words.
+    /// ### Default values:
+    /// * `punctuation` - A list of punctuation symbols, defaults to those in Latin and Germanic languages.
+    /// * `phrase_length` - The maximum length of the phrases, defaults to 3.
+    /// * `weights` - Every enabled algorithm, with a weight of 1.0.
+    /// * `fusion` - Defaults to `RankFusion::ReciprocalRank(60.0)`.
+    /// * `options` - Defaults to `EnsembleOptions::default()`.
+    WithDefaults(Text<'a>, Stopwords<'a>),
+
+    /// ## Arguments
+    /// 1. `text` - The text to be analyzed.
+    /// 2. `stop_words` - A list of stop words.
+    /// 3. `weights` - The algorithms to run and their weights in the fusion.
+    /// 4. `fusion` - How the rankings are fused.
+    /// ### Default values:
+    /// * `punctuation` - A list of punctuation symbols, defaults to those in Latin and Germanic languages.
+    /// * `phrase_length` - The maximum length of the phrases, defaults to 3.
+    /// * `options` - Defaults to `EnsembleOptions::default()`.
+    WithAlgorithms(Text<'a>, Stopwords<'a>, Weights<'a>, RankFusion),
+
+    /// ## Arguments
+    /// 1. `text` - The text to be analyzed.
+    /// 2. `stop_words` - A list of stop words.
+    /// 3. `punctuation` - Optional list of punctuation symbols.
+    /// 4. `phrase_length` - Optional maximum length of the phrases, also the YAKE n-gram size (3 when unset).
+    /// 5. `weights` - The algorithms to run and their weights in the fusion.
+    /// 6. `fusion` - How the rankings are fused.
+    /// ### Default values:
+    /// * `options` - Defaults to `EnsembleOptions::default()`.
+    All(
+        Text<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
+        PhraseLength,
+        Weights<'a>,
+        RankFusion,
+    ),
+
+    /// ## Arguments
+    /// 1. `text` - The text to be analyzed.
+    /// 2. `stop_words` - A list of stop words.
+    /// 3. `punctuation` - Optional list of punctuation symbols.
+    /// 4. `phrase_length` - Optional maximum length of the phrases.
+    /// 5. `weights` - The algorithms to run and their weights in the fusion.
+    /// 6. `fusion` - How the rankings are fused.
+    /// 7. `options` - The settings of the algorithms, see `EnsembleOptions`.
+    WithOptions(
+        Text<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
+        PhraseLength,
+        Weights<'a>,
+        RankFusion,
+        EnsembleOptions,
+    ),
+}
+
+impl<'a> EnsembleParams<'a> {
+    /// Returns the params to be used in the ensemble keyword extractor.
+    pub fn get_params(
+        &self,
+    ) -> (
+        Text<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
+        PhraseLength,
+        Weights<'a>,
+        RankFusion,
+        EnsembleOptions,
+    ) {
+        match self {
+            EnsembleParams::WithDefaults(text, stop_words) => (
+                *text,
+                *stop_words,
+                None,
+                Some(3),
+                DEFAULT_WEIGHTS,
+                RankFusion::default(),
+                EnsembleOptions::default(),
+            ),
+            EnsembleParams::WithAlgorithms(text, stop_words, weights, fusion) => (
+                *text,
+                *stop_words,
+                None,
+                Some(3),
+                *weights,
+                *fusion,
+                EnsembleOptions::default(),
+            ),
+            EnsembleParams::All(text, stop_words, punctuation, phrase_length, weights, fusion) => (
+                *text,
+                *stop_words,
+                *punctuation,
+                *phrase_length,
+                *weights,
+                *fusion,
+                EnsembleOptions::default(),
+            ),
+            EnsembleParams::WithOptions(
+                text,
+                stop_words,
+                punctuation,
+                phrase_length,
+                weights,
+                fusion,
+                options,
+            ) => (
+                *text,
+                *stop_words,
+                *punctuation,
+                *phrase_length,
+                *weights,
+                *fusion,
+                *options,
+            ),
+        }
+    }
+}
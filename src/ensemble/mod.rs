@@ -0,0 +1,98 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::HashMap;
+
+mod ensemble_logic;
+pub mod ensemble_params;
+use ensemble_logic::{normalise_keyword, AlgorithmRanks, EnsembleLogic};
+pub use ensemble_params::{EnsembleAlgorithm, EnsembleOptions, EnsembleParams, RankFusion};
+
+use crate::{
+    common::{get_ranked_scores, get_ranked_strings, get_scores_above},
+    normalization::ScoreNormalization,
+};
+
+/// Runs several algorithms on the same text and fuses their rankings.
+///
+/// Keyphrases are matched across algorithms by their normalised form: lowercased words without surrounding punctuation.
+pub struct Ensemble {
+    keyword_rank: HashMap<String, f32>,
+    algorithm_ranks: AlgorithmRanks,
+}
+
+impl Ensemble {
+    /// Create a new Ensemble instance.
+    pub fn new(params: EnsembleParams) -> Self {
+        let (text, stop_words, punctuation, phrase_length, weights, fusion, options) =
+            params.get_params();
+        let (keyword_rank, algorithm_ranks) = EnsembleLogic::build_ensemble(
+            text,
+            stop_words,
+            punctuation,
+            phrase_length,
+            weights,
+            fusion,
+            options,
+        );
+
+        Self {
+            keyword_rank,
+            algorithm_ranks,
+        }
+    }
+
+    /// Normalises the fused keyword scores, so they can be compared across algorithms.
+    pub fn with_normalization(mut self, normalization: ScoreNormalization) -> Self {
+        self.keyword_rank = normalization.normalize(&self.keyword_rank);
+        self
+    }
+
+    /// Gets the fused score of a keyword, matched by normalised form.
+    pub fn get_keyword_score(&self, keyword: &str) -> f32 {
+        *self
+            .keyword_rank
+            .get(&normalise_keyword(keyword))
+            .unwrap_or(&0.0)
+    }
+
+    /// Gets the top n keywords with the highest fused score.
+    pub fn get_ranked_keywords(&self, n: usize) -> Vec<String> {
+        get_ranked_strings(&self.keyword_rank, n)
+    }
+
+    /// Gets the top n keywords with the highest fused score and their scores.
+    pub fn get_ranked_keyword_scores(&self, n: usize) -> Vec<(String, f32)> {
+        get_ranked_scores(&self.keyword_rank, n)
+    }
+
+    /// Gets the keywords whose score is at least the threshold, sorted by score.
+    pub fn get_keywords_above(&self, threshold: f32) -> Vec<(String, f32)> {
+        get_scores_above(&self.keyword_rank, threshold)
+    }
+
+    /// Gets the fused keyword scores map.
+    pub fn get_keyword_scores_map(&self) -> &HashMap<String, f32> {
+        &self.keyword_rank
+    }
+
+    /// Gets the scores of a single algorithm keyed by normalised form, if it took part in the ensemble.
+    pub fn get_algorithm_scores_map(
+        &self,
+        algorithm: EnsembleAlgorithm,
+    ) -> Option<&HashMap<String, f32>> {
+        self.algorithm_ranks.get(&algorithm)
+    }
+}
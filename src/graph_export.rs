@@ -0,0 +1,273 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+/// The format of an exported word graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT language.
+    Dot,
+
+    /// GraphML XML format.
+    GraphMl,
+
+    /// Node-link JSON, as read by networkx or d3.
+    Json,
+}
+
+/// A word of the graph with its score and frequency.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphNode {
+    /// The word.
+    pub id: String,
+    /// The score of the word in the algorithm that built the graph.
+    pub score: f32,
+    /// Number of occurrences of the word in the text.
+    pub frequency: usize,
+}
+
+/// A weighted edge between two words.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphEdge {
+    /// The word where the edge starts.
+    pub source: String,
+    /// The word where the edge ends.
+    pub target: String,
+    /// The weight of the edge.
+    pub weight: f32,
+}
+
+/// Word graph that can be exported to DOT, GraphML or JSON, with nodes sorted by id and edges by source and target.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordGraph {
+    directed: bool,
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Quotes the non-finite numbers, which DOT does not accept as numerals.
+fn format_dot_number(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value)
+    }
+}
+
+/// Writes the non-finite numbers as the XML Schema float special values.
+fn format_xml_number(value: f32) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "INF" } else { "-INF" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes the non-finite numbers as null, since JSON has no NaN or infinity.
+fn format_json_number(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+fn escape_json(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut acc, c| {
+            match c {
+                '"' => acc.push_str("\\\""),
+                '\\' => acc.push_str("\\\\"),
+                '\n' => acc.push_str("\\n"),
+                '\r' => acc.push_str("\\r"),
+                '\t' => acc.push_str("\\t"),
+                c if (c as u32) < 0x20 => acc.push_str(&format!("\\u{:04x}", c as u32)),
+                c => acc.push(c),
+            }
+            acc
+        })
+}
+
+impl WordGraph {
+    /// Create a new WordGraph, in an undirected graph each edge should only be given once.
+    pub fn new(directed: bool, mut nodes: Vec<GraphNode>, mut edges: Vec<GraphEdge>) -> Self {
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        edges.sort_by(|a, b| {
+            a.source
+                .cmp(&b.source)
+                .then_with(|| a.target.cmp(&b.target))
+        });
+
+        Self {
+            directed,
+            nodes,
+            edges,
+        }
+    }
+
+    /// Removes the edges with a weight below the threshold, the nodes are kept.
+    pub fn prune(mut self, threshold: f32) -> Self {
+        self.edges.retain(|edge| edge.weight >= threshold);
+        self
+    }
+
+    /// Whether the edges of the graph are directed.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Gets the nodes of the graph.
+    pub fn get_nodes(&self) -> &[GraphNode] {
+        &self.nodes
+    }
+
+    /// Gets the edges of the graph.
+    pub fn get_edges(&self) -> &[GraphEdge] {
+        &self.edges
+    }
+
+    /// Exports the graph to the given format.
+    pub fn export(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::GraphMl => self.to_graphml(),
+            GraphFormat::Json => self.to_json(),
+        }
+    }
+
+    /// Exports the graph to the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let (graph_type, connector) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        std::iter::once(format!("{} {{", graph_type))
+            .chain(self.nodes.iter().map(|node| {
+                format!(
+                    "  \"{}\" [score={}, frequency={}];",
+                    escape_dot(&node.id),
+                    format_dot_number(node.score),
+                    node.frequency
+                )
+            }))
+            .chain(self.edges.iter().map(|edge| {
+                format!(
+                    "  \"{}\" {} \"{}\" [weight={}];",
+                    escape_dot(&edge.source),
+                    connector,
+                    escape_dot(&edge.target),
+                    format_dot_number(edge.weight)
+                )
+            }))
+            .chain(std::iter::once("}\n".to_string()))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Exports the graph to the GraphML XML format.
+    pub fn to_graphml(&self) -> String {
+        let header = [
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>".to_string(),
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">".to_string(),
+            "  <key id=\"score\" for=\"node\" attr.name=\"score\" attr.type=\"float\"/>"
+                .to_string(),
+            "  <key id=\"frequency\" for=\"node\" attr.name=\"frequency\" attr.type=\"int\"/>"
+                .to_string(),
+            "  <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"float\"/>"
+                .to_string(),
+            format!(
+                "  <graph id=\"G\" edgedefault=\"{}\">",
+                if self.directed {
+                    "directed"
+                } else {
+                    "undirected"
+                }
+            ),
+        ];
+        let footer = ["  </graph>".to_string(), "</graphml>\n".to_string()];
+
+        header
+            .into_iter()
+            .chain(self.nodes.iter().map(|node| {
+                format!(
+                    "    <node id=\"{}\"><data key=\"score\">{}</data><data key=\"frequency\">{}</data></node>",
+                    escape_xml(&node.id),
+                    format_xml_number(node.score),
+                    node.frequency
+                )
+            }))
+            .chain(self.edges.iter().map(|edge| {
+                format!(
+                    "    <edge source=\"{}\" target=\"{}\"><data key=\"weight\">{}</data></edge>",
+                    escape_xml(&edge.source),
+                    escape_xml(&edge.target),
+                    format_xml_number(edge.weight)
+                )
+            }))
+            .chain(footer)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Exports the graph to node-link JSON.
+    pub fn to_json(&self) -> String {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| {
+                format!(
+                    "{{\"id\":\"{}\",\"score\":{},\"frequency\":{}}}",
+                    escape_json(&node.id),
+                    format_json_number(node.score),
+                    node.frequency
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",");
+        let links = self
+            .edges
+            .iter()
+            .map(|edge| {
+                format!(
+                    "{{\"source\":\"{}\",\"target\":\"{}\",\"weight\":{}}}",
+                    escape_json(&edge.source),
+                    escape_json(&edge.target),
+                    format_json_number(edge.weight)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",");
+
+        format!(
+            "{{\"directed\":{},\"multigraph\":false,\"graph\":{{}},\"nodes\":[{}],\"links\":[{}]}}",
+            self.directed, nodes, links
+        )
+    }
+}
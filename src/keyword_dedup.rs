@@ -0,0 +1,159 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::{cmp::min, collections::HashMap, fmt, sync::Arc};
+
+use crate::{
+    common::sort_ranked_map,
+    similarity::{JaroWinkler, Levenshtein, SequenceMatcher},
+    stemmer::Stemmer,
+};
+
+/// A custom similarity function between two keywords.
+pub type SimilarityFn = Arc<dyn Fn(&str, &str) -> f32 + Send + Sync>;
+
+/// The similarity function used to drop near-duplicate keywords from the ranked keywords.
+#[derive(Clone, Default)]
+pub enum DedupStrategy {
+    /// Levenshtein ratio, the reference "leve" function.
+    #[default]
+    Levenshtein,
+
+    /// Jaro-Winkler similarity, the reference "jaro" function.
+    JaroWinkler,
+
+    /// Ratcliff-Obershelp sequence matcher ratio, the reference "seqm" function.
+    SequenceMatcher,
+
+    /// A custom similarity function between 0 and 1.
+    Custom(SimilarityFn),
+}
+
+impl fmt::Debug for DedupStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Levenshtein => f.write_str("Levenshtein"),
+            Self::JaroWinkler => f.write_str("JaroWinkler"),
+            Self::SequenceMatcher => f.write_str("SequenceMatcher"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl DedupStrategy {
+    /// Similarity between two keywords.
+    pub fn similarity(&self, keyword1: &str, keyword2: &str) -> f32 {
+        match self {
+            Self::Levenshtein => Levenshtein::new(keyword1, keyword2).ratio(),
+            Self::JaroWinkler => JaroWinkler::new(keyword1, keyword2).ratio(),
+            Self::SequenceMatcher => SequenceMatcher::new(keyword1, keyword2).ratio(),
+            Self::Custom(similarity) => similarity(keyword1, keyword2),
+        }
+    }
+
+    /// Whether two keywords are at least as similar as the threshold, Levenshtein stops early once it cannot be.
+    pub fn is_similar(&self, keyword1: &str, keyword2: &str, threshold: f32) -> bool {
+        match self {
+            Self::Levenshtein => Levenshtein::is_ratio_at_least(keyword1, keyword2, threshold),
+            _ => self.similarity(keyword1, keyword2) >= threshold,
+        }
+    }
+}
+
+/// Post-ranking removal of near-duplicate keywords, available on the ranked results of every algorithm.
+///
+/// Keywords are visited from the highest to the lowest score, and a keyword is dropped when it is
+/// a duplicate of one that was already kept.
+#[derive(Clone, Debug)]
+pub struct KeywordDedup {
+    /// The similarity function between two keywords, defaults to `DedupStrategy::Levenshtein`.
+    pub strategy: DedupStrategy,
+    /// Keywords at least this similar to a kept keyword are dropped, defaults to 0.85.
+    pub threshold: f32,
+    /// Drop keywords whose words have the same stems as a kept keyword, defaults to `None`.
+    pub stemmer: Option<Stemmer>,
+    /// Drop keywords whose words are contained in a kept keyword, defaults to false.
+    pub subsumption: bool,
+}
+
+impl Default for KeywordDedup {
+    fn default() -> Self {
+        Self {
+            strategy: DedupStrategy::default(),
+            threshold: 0.85,
+            stemmer: None,
+            subsumption: false,
+        }
+    }
+}
+
+/// Whether `words` is a contiguous run of `other_words`.
+fn is_subsumed(other_words: &[String], words: &[String]) -> bool {
+    !words.is_empty()
+        && other_words
+            .windows(words.len())
+            .any(|window| window == words)
+}
+
+impl KeywordDedup {
+    /// The words of a keyword, stemmed when stem equivalence is on and lowercased otherwise.
+    fn get_words(&self, keyword: &str) -> Vec<String> {
+        keyword
+            .split_whitespace()
+            .map(|word| match &self.stemmer {
+                Some(stemmer) => stemmer.stem(word),
+                None => word.to_lowercase(),
+            })
+            .collect()
+    }
+
+    fn is_duplicate(&self, kept: &[(&str, Vec<String>)], keyword: &str, words: &[String]) -> bool {
+        kept.iter().any(|(other, other_words)| {
+            (self.stemmer.is_some() && other_words.as_slice() == words)
+                || (self.subsumption && is_subsumed(other_words, words))
+                || self.strategy.is_similar(other, keyword, self.threshold)
+        })
+    }
+
+    /// Gets the top n keywords with the highest score and their scores, after dropping the duplicates.
+    pub fn get_ranked_scores(&self, scores: &HashMap<String, f32>, n: usize) -> Vec<(String, f32)> {
+        let capacity = min(scores.len(), n);
+        let mut kept = Vec::<(&str, Vec<String>)>::with_capacity(capacity);
+        let mut result = Vec::<(String, f32)>::with_capacity(capacity);
+
+        for (keyword, score) in sort_ranked_map(scores) {
+            if result.len() == capacity {
+                break;
+            }
+
+            let words = self.get_words(keyword);
+
+            if !self.is_duplicate(&kept, keyword, &words) {
+                kept.push((keyword, words));
+                result.push((keyword.to_string(), *score));
+            }
+        }
+
+        result
+    }
+
+    /// Gets the top n keywords with the highest score, after dropping the duplicates.
+    pub fn get_ranked_strings(&self, scores: &HashMap<String, f32>, n: usize) -> Vec<String> {
+        self.get_ranked_scores(scores, n)
+            .into_iter()
+            .map(|(keyword, _)| keyword)
+            .collect()
+    }
+}
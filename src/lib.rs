@@ -18,6 +18,17 @@ mod common;
 #[cfg(feature = "co_occurrence")]
 pub mod co_occurrence;
 
+#[cfg(any(
+    feature = "rake",
+    feature = "text_rank",
+    feature = "tf_idf",
+    feature = "yake"
+))]
+pub mod ensemble;
+
+#[cfg(any(feature = "co_occurrence", feature = "text_rank"))]
+pub mod graph_export;
+
 #[cfg(feature = "rake")]
 pub mod rake;
 
@@ -30,6 +41,16 @@ pub mod tf_idf;
 #[cfg(feature = "yake")]
 pub mod yake;
 
+pub mod keyword_dedup;
+
+pub mod mmr;
+
+pub mod normalization;
+
+pub mod similarity;
+
+pub mod stemmer;
+
 pub mod tokenizer;
 
 #[cfg(test)]
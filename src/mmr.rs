@@ -0,0 +1,220 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+#[cfg(feature = "co_occurrence")]
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+#[cfg(feature = "co_occurrence")]
+use crate::co_occurrence::CoOccurrence;
+use crate::{common::sort_ranked_map, keyword_dedup::DedupStrategy};
+
+/// The similarity between two keywords, used to penalise redundant keywords.
+#[derive(Clone)]
+pub enum MmrSimilarity<'a> {
+    /// String similarity between the keywords.
+    String(DedupStrategy),
+
+    /// Cosine similarity between the keywords as bags of words weighted by TF-IDF scores, such as `TfIdf::get_word_scores_map`.
+    TfIdfCosine(&'a HashMap<String, f32>),
+
+    /// Cosine similarity between the sums of the co-occurrence word vectors of the keywords.
+    #[cfg(feature = "co_occurrence")]
+    CoOccurrence(&'a CoOccurrence),
+}
+
+impl Default for MmrSimilarity<'_> {
+    fn default() -> Self {
+        Self::String(DedupStrategy::default())
+    }
+}
+
+/// Maximal Marginal Relevance re-ranker, which diversifies the ranked keywords of any algorithm.
+///
+/// Each pick maximises `lambda * relevance - (1 - lambda) * redundancy`, where the relevance is the
+/// min-max normalised score and the redundancy the highest similarity to an already picked keyword.
+#[derive(Clone)]
+pub struct Mmr<'a> {
+    /// Trade-off between relevance (1.0) and diversity (0.0), defaults to 0.5.
+    pub lambda: f32,
+    /// The similarity between two keywords, defaults to the Levenshtein ratio.
+    pub similarity: MmrSimilarity<'a>,
+}
+
+impl Default for Mmr<'_> {
+    fn default() -> Self {
+        Self {
+            lambda: 0.5,
+            similarity: MmrSimilarity::default(),
+        }
+    }
+}
+
+/// A keyword prepared for the similarity, so vectors are only built once.
+enum KeywordVector<'b> {
+    Text(&'b str),
+    Sparse(HashMap<String, f32>),
+    #[cfg(feature = "co_occurrence")]
+    Indexed(Vec<(usize, f32)>),
+}
+
+struct MmrCandidate<'b> {
+    keyword: &'b str,
+    score: f32,
+    relevance: f32,
+    vector: KeywordVector<'b>,
+    redundancy: f32,
+}
+
+fn sparse_cosine(vector1: &HashMap<String, f32>, vector2: &HashMap<String, f32>) -> f32 {
+    let dot = vector1
+        .iter()
+        .filter_map(|(word, value)| vector2.get(word).map(|other| value * other))
+        .sum::<f32>();
+
+    if dot == 0.0 {
+        return 0.0;
+    }
+
+    let norm1 = vector1.values().map(|v| v * v).sum::<f32>().sqrt();
+    let norm2 = vector2.values().map(|v| v * v).sum::<f32>().sqrt();
+    dot / (norm1 * norm2)
+}
+
+/// Cosine similarity between two vectors given as (dimension, value) pairs sorted by dimension.
+#[cfg(feature = "co_occurrence")]
+fn indexed_cosine(vector1: &[(usize, f32)], vector2: &[(usize, f32)]) -> f32 {
+    let mut other = vector2.iter().peekable();
+    let dot = vector1.iter().fold(0.0_f32, |dot, (dimension, value)| {
+        while other.next_if(|(d, _)| d < dimension).is_some() {}
+
+        match other.peek() {
+            Some((d, other_value)) if d == dimension => dot + value * other_value,
+            _ => dot,
+        }
+    });
+
+    if dot == 0.0 {
+        return 0.0;
+    }
+
+    let norm1 = vector1.iter().map(|(_, v)| v * v).sum::<f32>().sqrt();
+    let norm2 = vector2.iter().map(|(_, v)| v * v).sum::<f32>().sqrt();
+    dot / (norm1 * norm2)
+}
+
+impl<'a> MmrSimilarity<'a> {
+    fn get_vector<'b>(&self, keyword: &'b str) -> KeywordVector<'b> {
+        match self {
+            Self::String(_) => KeywordVector::Text(keyword),
+            Self::TfIdfCosine(scores) => KeywordVector::Sparse(keyword.split_whitespace().fold(
+                HashMap::new(),
+                |mut acc, word| {
+                    let word = word.to_lowercase();
+                    let score = scores.get(&word).copied().unwrap_or(0.0);
+                    *acc.entry(word).or_insert(0.0) += score;
+                    acc
+                },
+            )),
+            #[cfg(feature = "co_occurrence")]
+            Self::CoOccurrence(co_occurrence) => KeywordVector::Indexed(
+                keyword
+                    .split_whitespace()
+                    .filter_map(|word| co_occurrence.get_sparse_word_vector(&word.to_lowercase()))
+                    .flatten()
+                    .fold(BTreeMap::new(), |mut acc, (dimension, value)| {
+                        *acc.entry(dimension).or_insert(0.0) += value;
+                        acc
+                    })
+                    .into_iter()
+                    .collect(),
+            ),
+        }
+    }
+
+    fn get_similarity(&self, vector1: &KeywordVector, vector2: &KeywordVector) -> f32 {
+        match (self, vector1, vector2) {
+            (Self::String(strategy), KeywordVector::Text(a), KeywordVector::Text(b)) => {
+                strategy.similarity(a, b)
+            }
+            (_, KeywordVector::Sparse(a), KeywordVector::Sparse(b)) => sparse_cosine(a, b),
+            #[cfg(feature = "co_occurrence")]
+            (_, KeywordVector::Indexed(a), KeywordVector::Indexed(b)) => indexed_cosine(a, b),
+            _ => unreachable!("the keyword vectors are built by the same similarity"),
+        }
+    }
+}
+
+impl<'a> Mmr<'a> {
+    /// Gets the top n keywords in the order they were picked, with their original scores.
+    pub fn get_ranked_scores(&self, scores: &HashMap<String, f32>, n: usize) -> Vec<(String, f32)> {
+        let ranked = sort_ranked_map(scores);
+        let (min, max) = ranked.iter().fold(
+            (f32::INFINITY, f32::NEG_INFINITY),
+            |(min, max), (_, score)| (min.min(**score), max.max(**score)),
+        );
+        let range = max - min;
+        let mut candidates = ranked
+            .into_iter()
+            .map(|(keyword, score)| MmrCandidate {
+                keyword,
+                score: *score,
+                relevance: if range > 0.0 {
+                    (score - min) / range
+                } else {
+                    1.0
+                },
+                vector: self.similarity.get_vector(keyword),
+                redundancy: 0.0,
+            })
+            .collect::<Vec<MmrCandidate>>();
+        let mut result = Vec::<(String, f32)>::with_capacity(n.min(candidates.len()));
+
+        while result.len() < n && !candidates.is_empty() {
+            let (best, _) = candidates.iter().enumerate().fold(
+                (0, f32::NEG_INFINITY),
+                |(best, best_mmr), (i, candidate)| {
+                    let mmr = self.lambda * candidate.relevance
+                        - (1.0 - self.lambda) * candidate.redundancy;
+
+                    if mmr > best_mmr {
+                        (i, mmr)
+                    } else {
+                        (best, best_mmr)
+                    }
+                },
+            );
+            let picked = candidates.remove(best);
+
+            candidates.iter_mut().for_each(|candidate| {
+                candidate.redundancy = candidate.redundancy.max(
+                    self.similarity
+                        .get_similarity(&picked.vector, &candidate.vector),
+                );
+            });
+            result.push((picked.keyword.to_string(), picked.score));
+        }
+
+        result
+    }
+
+    /// Gets the top n keywords in the order they were picked.
+    pub fn get_ranked_strings(&self, scores: &HashMap<String, f32>, n: usize) -> Vec<String> {
+        self.get_ranked_scores(scores, n)
+            .into_iter()
+            .map(|(keyword, _)| keyword)
+            .collect()
+    }
+}
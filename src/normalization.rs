@@ -0,0 +1,98 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::HashMap;
+
+/// Rescales the scores of a result set, so they can be compared across algorithms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScoreNormalization {
+    /// Rescales the scores to [0, 1], all scores become 1 when they are equal.
+    MinMax,
+
+    /// Standard score, the distance to the mean in population standard deviations, 0 when all scores are equal.
+    ZScore,
+
+    /// Fraction of the scores that are lower or equal, in (0, 1], so the top keyword scores 1 and ties share a score.
+    RankPercentile,
+
+    /// Softmax of the scores, which then sum to 1.
+    Softmax,
+}
+
+impl ScoreNormalization {
+    /// Normalises the scores of a result set.
+    pub fn normalize(&self, scores: &HashMap<String, f32>) -> HashMap<String, f32> {
+        if scores.is_empty() {
+            return HashMap::new();
+        }
+
+        let length = scores.len() as f32;
+        let rescale = |f: &dyn Fn(f32) -> f32| {
+            scores
+                .iter()
+                .map(|(keyword, score)| (keyword.to_string(), f(*score)))
+                .collect::<HashMap<String, f32>>()
+        };
+
+        match self {
+            Self::MinMax => {
+                let (min, max) = scores
+                    .values()
+                    .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), score| {
+                        (min.min(*score), max.max(*score))
+                    });
+                let range = max - min;
+                rescale(&|score| {
+                    if range > 0.0 {
+                        (score - min) / range
+                    } else {
+                        1.0
+                    }
+                })
+            }
+            Self::ZScore => {
+                let mean = scores.values().sum::<f32>() / length;
+                let std = (scores
+                    .values()
+                    .map(|score| (score - mean).powi(2))
+                    .sum::<f32>()
+                    / length)
+                    .sqrt();
+                rescale(&|score| {
+                    if std > 0.0 {
+                        (score - mean) / std
+                    } else {
+                        0.0
+                    }
+                })
+            }
+            Self::RankPercentile => {
+                let mut sorted = scores.values().copied().collect::<Vec<f32>>();
+                sorted.sort_by(|a, b| a.total_cmp(b));
+                rescale(&|score| sorted.partition_point(|other| *other <= score) as f32 / length)
+            }
+            Self::Softmax => {
+                let max = scores
+                    .values()
+                    .fold(f32::NEG_INFINITY, |max, score| max.max(*score));
+                let sum = scores
+                    .values()
+                    .map(|score| (score - max).exp())
+                    .sum::<f32>();
+                rescale(&|score| (score - max).exp() / sum)
+            }
+        }
+    }
+}
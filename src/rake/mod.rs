@@ -20,7 +20,11 @@ pub mod rake_params;
 use rake_logic::RakeLogic;
 pub use rake_params::RakeParams;
 
-use crate::common::{get_ranked_scores, get_ranked_strings};
+use crate::{
+    common::{get_ranked_scores, get_ranked_strings, get_scores_above},
+    keyword_dedup::KeywordDedup,
+    normalization::ScoreNormalization,
+};
 
 pub struct Rake {
     word_scores: HashMap<String, f32>,
@@ -40,6 +44,13 @@ impl Rake {
         }
     }
 
+    /// Normalises the word and phrase scores, so they can be compared across algorithms.
+    pub fn with_normalization(mut self, normalization: ScoreNormalization) -> Self {
+        self.word_scores = normalization.normalize(&self.word_scores);
+        self.phrase_scores = normalization.normalize(&self.phrase_scores);
+        self
+    }
+
     /// Gets the top n words with the highest score.
     pub fn get_ranked_keyword(&self, n: usize) -> Vec<String> {
         get_ranked_strings(&self.word_scores, n)
@@ -50,6 +61,11 @@ impl Rake {
         get_ranked_scores(&self.word_scores, n)
     }
 
+    /// Gets the words whose score is at least the threshold, sorted by score.
+    pub fn get_keywords_above(&self, threshold: f32) -> Vec<(String, f32)> {
+        get_scores_above(&self.word_scores, threshold)
+    }
+
     /// Gets the top n phrases with the highest score.
     pub fn get_ranked_phrases(&self, n: usize) -> Vec<String> {
         get_ranked_strings(&self.phrase_scores, n)
@@ -60,6 +76,25 @@ impl Rake {
         get_ranked_scores(&self.phrase_scores, n)
     }
 
+    /// Gets the phrases whose score is at least the threshold, sorted by score.
+    pub fn get_phrases_above(&self, threshold: f32) -> Vec<(String, f32)> {
+        get_scores_above(&self.phrase_scores, threshold)
+    }
+
+    /// Gets the top n phrases with the highest score, after dropping the near-duplicates.
+    pub fn get_deduplicated_phrases(&self, n: usize, dedup: &KeywordDedup) -> Vec<String> {
+        dedup.get_ranked_strings(&self.phrase_scores, n)
+    }
+
+    /// Gets the top n phrases with the highest score and their scores, after dropping the near-duplicates.
+    pub fn get_deduplicated_phrase_scores(
+        &self,
+        n: usize,
+        dedup: &KeywordDedup,
+    ) -> Vec<(String, f32)> {
+        dedup.get_ranked_scores(&self.phrase_scores, n)
+    }
+
     /// Gets the score of a word.
     pub fn get_keyword_score(&self, word: &str) -> f32 {
         *self.word_scores.get(word).unwrap_or(&0.0)
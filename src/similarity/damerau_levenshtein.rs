@@ -0,0 +1,106 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Unrestricted Damerau-Levenshtein distance, where transposed graphemes may be edited again.
+fn calculate_distance(graphemes1: &[&str], graphemes2: &[&str]) -> usize {
+    let (len1, len2) = (graphemes1.len(), graphemes2.len());
+    let infinity = len1 + len2;
+    let mut table = vec![vec![0; len2 + 2]; len1 + 2];
+    table[0][0] = infinity;
+
+    (0..=len1).for_each(|i| {
+        table[i + 1][0] = infinity;
+        table[i + 1][1] = i;
+    });
+    (0..=len2).for_each(|j| {
+        table[0][j + 1] = infinity;
+        table[1][j + 1] = j;
+    });
+
+    let mut last_rows = HashMap::<&str, usize>::new();
+
+    (1..=len1).for_each(|i| {
+        let mut last_column = 0;
+
+        (1..=len2).for_each(|j| {
+            let last_row = *last_rows.get(graphemes2[j - 1]).unwrap_or(&0);
+            let previous_column = last_column;
+            let cost = if graphemes1[i - 1] == graphemes2[j - 1] {
+                last_column = j;
+                0
+            } else {
+                1
+            };
+
+            table[i + 1][j + 1] = min(
+                min(table[i][j] + cost, table[i + 1][j] + 1),
+                min(
+                    table[i][j + 1] + 1,
+                    table[last_row][previous_column]
+                        + (i - last_row - 1)
+                        + 1
+                        + (j - previous_column - 1),
+                ),
+            );
+        });
+
+        last_rows.insert(graphemes1[i - 1], i);
+    });
+
+    table[len1 + 1][len2 + 1]
+}
+
+/// Grapheme-aware Damerau-Levenshtein distance, which also counts the transposition of two graphemes as one edit.
+pub struct DamerauLevenshtein<'a>(&'a str, &'a str, usize);
+
+impl<'a> DamerauLevenshtein<'a> {
+    /// Create a new DamerauLevenshtein instance.
+    pub fn new(str1: &'a str, str2: &'a str) -> Self {
+        Self(
+            str1,
+            str2,
+            calculate_distance(
+                &str1.graphemes(true).collect::<Vec<&str>>(),
+                &str2.graphemes(true).collect::<Vec<&str>>(),
+            ),
+        )
+    }
+
+    /// The edit distance, in graphemes.
+    pub fn distance(&self) -> usize {
+        self.2
+    }
+
+    /// Similarity between 0 and 1, one minus the distance over the length of the longest string.
+    pub fn ratio(&self) -> f32 {
+        let max_len = max(
+            self.0.graphemes(true).count(),
+            self.1.graphemes(true).count(),
+        );
+
+        if max_len == 0 {
+            return 1.0;
+        }
+
+        1.0 - (self.2 as f32 / max_len as f32)
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::cmp::{max, min};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+const PREFIX_SCALE: f32 = 0.1;
+const MAX_PREFIX: usize = 4;
+const BOOST_THRESHOLD: f32 = 0.7;
+
+fn calculate_jaro(graphemes1: &[&str], graphemes2: &[&str]) -> f32 {
+    let (len1, len2) = (graphemes1.len(), graphemes2.len());
+
+    if len1 == 0 || len2 == 0 {
+        return if len1 == len2 { 1.0 } else { 0.0 };
+    }
+
+    let window = (max(len1, len2) / 2).saturating_sub(1);
+    let mut matched2 = vec![false; len2];
+    let matches1 = graphemes1
+        .iter()
+        .enumerate()
+        .filter_map(|(i, g1)| {
+            let start = i.saturating_sub(window);
+            let end = min(i + window + 1, len2);
+            (start..end)
+                .find(|j| !matched2[*j] && graphemes2[*j] == *g1)
+                .map(|j| {
+                    matched2[j] = true;
+                    *g1
+                })
+        })
+        .collect::<Vec<&str>>();
+
+    if matches1.is_empty() {
+        return 0.0;
+    }
+
+    let transpositions = graphemes2
+        .iter()
+        .zip(matched2.iter())
+        .filter(|(_, matched)| **matched)
+        .zip(matches1.iter())
+        .filter(|((g2, _), g1)| *g2 != *g1)
+        .count();
+    let m = matches1.len() as f32;
+
+    (m / len1 as f32 + m / len2 as f32 + (m - transpositions as f32 / 2.0) / m) / 3.0
+}
+
+/// Grapheme-aware Jaro and Jaro-Winkler similarities.
+pub struct JaroWinkler<'a>(&'a str, &'a str);
+
+impl<'a> JaroWinkler<'a> {
+    /// Create a new JaroWinkler instance.
+    pub fn new(str1: &'a str, str2: &'a str) -> Self {
+        Self(str1, str2)
+    }
+
+    /// Jaro similarity, from the matching graphemes and their transpositions.
+    pub fn jaro(&self) -> f32 {
+        calculate_jaro(
+            &self.0.graphemes(true).collect::<Vec<&str>>(),
+            &self.1.graphemes(true).collect::<Vec<&str>>(),
+        )
+    }
+
+    /// Jaro similarity boosted by the common prefix, when the Jaro similarity is above 0.7.
+    pub fn ratio(&self) -> f32 {
+        let graphemes1 = self.0.graphemes(true).collect::<Vec<&str>>();
+        let graphemes2 = self.1.graphemes(true).collect::<Vec<&str>>();
+        let jaro = calculate_jaro(&graphemes1, &graphemes2);
+
+        if jaro <= BOOST_THRESHOLD {
+            return jaro;
+        }
+
+        let prefix = graphemes1
+            .iter()
+            .zip(graphemes2.iter())
+            .take(MAX_PREFIX)
+            .take_while(|(g1, g2)| g1 == g2)
+            .count();
+        jaro + prefix as f32 * PREFIX_SCALE * (1.0 - jaro)
+    }
+}
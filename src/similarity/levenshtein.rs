@@ -0,0 +1,162 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::cmp::{max, min};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+fn calculate_distance(str1: &str, str2: &str) -> usize {
+    if (str1.is_empty() && str2.is_empty()) || str1 == str2 {
+        return 0;
+    }
+
+    let graphemes1 = str1.graphemes(true);
+    let graphemes2 = str2.graphemes(true);
+    let len = graphemes2.clone().count() + 1;
+    let mut prev_row = (0..len).collect::<Vec<usize>>();
+
+    let last_row = graphemes1
+        .enumerate()
+        .fold(prev_row.clone(), |row, (i, char1)| {
+            let mut new_row = vec![i + 1; len];
+            graphemes2.clone().enumerate().for_each(|(j, char2)| {
+                let cost = if char1 == char2 { 0 } else { 1 };
+                new_row[j + 1] = min(row[j + 1] + 1, min(new_row[j] + 1, row[j] + cost));
+            });
+            prev_row = row;
+            new_row
+        });
+
+    last_row[len - 1]
+}
+
+/// Only fills the cells within `max_distance` of the diagonal, and stops as soon as a whole band is above it.
+fn calculate_bounded_distance(
+    graphemes1: &[&str],
+    graphemes2: &[&str],
+    max_distance: usize,
+) -> Option<usize> {
+    let (len1, len2) = (graphemes1.len(), graphemes2.len());
+
+    if len1.abs_diff(len2) > max_distance {
+        return None;
+    }
+
+    let above = max_distance + 1;
+    let mut prev_row = (0..=len2)
+        .map(|j| if j <= max_distance { j } else { above })
+        .collect::<Vec<usize>>();
+    let mut row = vec![above; len2 + 1];
+
+    for i in 1..=len1 {
+        let (start, end) = (
+            max(1, i.saturating_sub(max_distance)),
+            min(len2, i + max_distance),
+        );
+        // Only the cells next to the band are read outside of it, so they are the only ones reset.
+        row[0] = if i <= max_distance { i } else { above };
+        if start > 1 {
+            row[start - 1] = above;
+        }
+        if end < len2 {
+            row[end + 1] = above;
+        }
+        let mut row_min = row[0];
+
+        for j in start..=end {
+            let cost = usize::from(graphemes1[i - 1] != graphemes2[j - 1]);
+            row[j] = min(
+                min(prev_row[j] + 1, row[j - 1] + 1),
+                min(prev_row[j - 1] + cost, above),
+            );
+            row_min = min(row_min, row[j]);
+        }
+
+        if row_min > max_distance {
+            return None;
+        }
+
+        std::mem::swap(&mut prev_row, &mut row);
+    }
+
+    Some(prev_row[len2]).filter(|distance| *distance <= max_distance)
+}
+
+/// Grapheme-aware Levenshtein edit distance between two strings.
+pub struct Levenshtein<'a>(&'a str, &'a str, usize);
+
+impl<'a> Levenshtein<'a> {
+    /// Create a new Levenshtein instance, computing the full edit distance.
+    pub fn new(str1: &'a str, str2: &'a str) -> Self {
+        Self(str1, str2, calculate_distance(str1, str2))
+    }
+
+    /// The edit distance, in graphemes.
+    pub fn distance(&self) -> usize {
+        self.2
+    }
+
+    /// Similarity between 0 and 1, one minus the distance over the length of the longest string.
+    pub fn ratio(&self) -> f32 {
+        let max_len = max(
+            self.0.graphemes(true).count(),
+            self.1.graphemes(true).count(),
+        );
+
+        if max_len == 0 {
+            return 1.0;
+        }
+
+        1.0 - (self.2 as f32 / max_len as f32)
+    }
+
+    /// The edit distance if it is at most `max_distance`, without filling the full table otherwise.
+    pub fn bounded_distance(str1: &str, str2: &str, max_distance: usize) -> Option<usize> {
+        calculate_bounded_distance(
+            &str1.graphemes(true).collect::<Vec<&str>>(),
+            &str2.graphemes(true).collect::<Vec<&str>>(),
+            max_distance,
+        )
+    }
+
+    /// Whether the ratio of the two strings is at least the threshold, using the bounded distance.
+    pub fn is_ratio_at_least(str1: &str, str2: &str, threshold: f32) -> bool {
+        let graphemes1 = str1.graphemes(true).collect::<Vec<&str>>();
+        let graphemes2 = str2.graphemes(true).collect::<Vec<&str>>();
+        let max_len = max(graphemes1.len(), graphemes2.len());
+
+        if max_len == 0 {
+            return true;
+        }
+
+        // Largest distance whose ratio, computed as in `ratio`, is still above the threshold.
+        let ratio = |distance: usize| 1.0 - (distance as f32 / max_len as f32);
+        let mut max_distance = min(
+            max_len,
+            ((1.0 - threshold.clamp(0.0, 1.0)) * max_len as f32).round() as usize,
+        );
+
+        while max_distance > 0 && ratio(max_distance) < threshold {
+            max_distance -= 1;
+        }
+
+        while max_distance < max_len && ratio(max_distance + 1) >= threshold {
+            max_distance += 1;
+        }
+
+        ratio(max_distance) >= threshold
+            && calculate_bounded_distance(&graphemes1, &graphemes2, max_distance).is_some()
+    }
+}
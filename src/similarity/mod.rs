@@ -0,0 +1,25 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+mod damerau_levenshtein;
+mod jaro_winkler;
+mod levenshtein;
+mod ngram_jaccard;
+mod sequence_matcher;
+pub use damerau_levenshtein::DamerauLevenshtein;
+pub use jaro_winkler::JaroWinkler;
+pub use levenshtein::Levenshtein;
+pub use ngram_jaccard::NgramJaccard;
+pub use sequence_matcher::SequenceMatcher;
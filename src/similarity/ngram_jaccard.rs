@@ -0,0 +1,52 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::HashSet;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The character n-grams of a string, a string shorter than n is its only n-gram.
+fn get_ngrams(text: &str, n: usize) -> HashSet<String> {
+    let graphemes = text.graphemes(true).collect::<Vec<&str>>();
+
+    if graphemes.len() <= n {
+        return HashSet::from([graphemes.concat()]);
+    }
+
+    graphemes.windows(n).map(|window| window.concat()).collect()
+}
+
+/// Jaccard similarity of the sets of character n-grams of two strings.
+pub struct NgramJaccard<'a>(&'a str, &'a str, usize);
+
+impl<'a> NgramJaccard<'a> {
+    /// Create a new NgramJaccard instance, with grapheme n-grams of size `n` (at least 1).
+    pub fn new(str1: &'a str, str2: &'a str, n: usize) -> Self {
+        Self(str1, str2, n.max(1))
+    }
+
+    /// Similarity between 0 and 1, the shared n-grams over all the n-grams of both strings.
+    pub fn ratio(&self) -> f32 {
+        let ngrams1 = get_ngrams(self.0, self.2);
+        let ngrams2 = get_ngrams(self.1, self.2);
+        let union = ngrams1.union(&ngrams2).count();
+
+        if union == 0 {
+            return 1.0;
+        }
+
+        ngrams1.intersection(&ngrams2).count() as f32 / union as f32
+    }
+}
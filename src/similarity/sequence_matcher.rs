@@ -0,0 +1,103 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Longest common block of the two ranges, as (start1, start2, length).
+fn find_longest_match(
+    graphemes1: &[&str],
+    graphemes2: &[&str],
+    (start1, end1): (usize, usize),
+    (start2, end2): (usize, usize),
+) -> (usize, usize, usize) {
+    let mut lengths = vec![0; end2 - start2 + 1];
+
+    (start1..end1).fold((start1, start2, 0), |best, i| {
+        let mut previous = 0;
+
+        (start2..end2).fold(best, |best, j| {
+            let current = lengths[j - start2 + 1];
+            lengths[j - start2 + 1] = if graphemes1[i] == graphemes2[j] {
+                previous + 1
+            } else {
+                0
+            };
+            previous = current;
+            let length = lengths[j - start2 + 1];
+
+            if length > best.2 {
+                (i + 1 - length, j + 1 - length, length)
+            } else {
+                best
+            }
+        })
+    })
+}
+
+/// Total length of the matching blocks, found recursively on both sides of the longest match.
+fn count_matches(
+    graphemes1: &[&str],
+    graphemes2: &[&str],
+    range1: (usize, usize),
+    range2: (usize, usize),
+) -> usize {
+    if range1.0 >= range1.1 || range2.0 >= range2.1 {
+        return 0;
+    }
+
+    let (i, j, length) = find_longest_match(graphemes1, graphemes2, range1, range2);
+
+    if length == 0 {
+        return 0;
+    }
+
+    length
+        + count_matches(graphemes1, graphemes2, (range1.0, i), (range2.0, j))
+        + count_matches(
+            graphemes1,
+            graphemes2,
+            (i + length, range1.1),
+            (j + length, range2.1),
+        )
+}
+
+/// Grapheme-aware Ratcliff-Obershelp similarity, as Python's `difflib.SequenceMatcher` without junk heuristics.
+pub struct SequenceMatcher<'a>(&'a str, &'a str);
+
+impl<'a> SequenceMatcher<'a> {
+    /// Create a new SequenceMatcher instance.
+    pub fn new(str1: &'a str, str2: &'a str) -> Self {
+        Self(str1, str2)
+    }
+
+    /// Ratcliff-Obershelp similarity, twice the number of matching graphemes over the total number of graphemes.
+    pub fn ratio(&self) -> f32 {
+        let graphemes1 = self.0.graphemes(true).collect::<Vec<&str>>();
+        let graphemes2 = self.1.graphemes(true).collect::<Vec<&str>>();
+        let total = graphemes1.len() + graphemes2.len();
+
+        if total == 0 {
+            return 1.0;
+        }
+
+        let matches = count_matches(
+            &graphemes1,
+            &graphemes2,
+            (0, graphemes1.len()),
+            (0, graphemes2.len()),
+        );
+        2.0 * matches as f32 / total as f32
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::{fmt, sync::Arc};
+
+/// A custom stemmer function, which receives the lowercased word.
+pub type StemmerFn = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// The stemmer used to reduce words to their lexical form.
+#[derive(Clone, Default)]
+pub enum Stemmer {
+    /// Only lowercases the words.
+    Lowercase,
+
+    /// Lowercases the words and removes the plural "s" of words longer than three letters, as the reference YAKE does.
+    #[default]
+    Plural,
+
+    /// Porter stemmer for English, words with non ASCII letters are only lowercased.
+    Porter,
+
+    /// A custom stemmer, which receives the lowercased word.
+    Custom(StemmerFn),
+}
+
+impl fmt::Debug for Stemmer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lowercase => f.write_str("Lowercase"),
+            Self::Plural => f.write_str("Plural"),
+            Self::Porter => f.write_str("Porter"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl Stemmer {
+    /// Gets the stem of a word.
+    pub fn stem(&self, word: &str) -> String {
+        let word = word.to_lowercase();
+
+        match self {
+            Self::Lowercase => word,
+            Self::Plural => {
+                if word.ends_with('s') && word.chars().count() > 3 {
+                    word[..word.len() - 1].to_string()
+                } else {
+                    word
+                }
+            }
+            Self::Porter => {
+                if word.bytes().all(|b| b.is_ascii_lowercase()) {
+                    PorterStemmer::new(&word).stem()
+                } else {
+                    word
+                }
+            }
+            Self::Custom(stem) => stem(&word),
+        }
+    }
+}
+
+/// Porter (1980) suffix stripping algorithm, over lowercase ASCII letters.
+struct PorterStemmer {
+    b: Vec<u8>,
+    k: usize,
+    j: usize,
+}
+
+impl PorterStemmer {
+    fn new(word: &str) -> Self {
+        Self {
+            b: word.as_bytes().to_vec(),
+            k: word.len().saturating_sub(1),
+            j: 0,
+        }
+    }
+
+    fn stem(mut self) -> String {
+        if self.b.len() <= 2 {
+            return String::from_utf8(self.b).unwrap_or_default();
+        }
+
+        self.step1ab();
+
+        if self.k > 0 {
+            self.step1c();
+            self.step2();
+            self.step3();
+            self.step4();
+            self.step5();
+        }
+
+        self.b.truncate(self.k + 1);
+        String::from_utf8(self.b).unwrap_or_default()
+    }
+
+    fn is_consonant(&self, i: usize) -> bool {
+        match self.b[i] {
+            b'a' | b'e' | b'i' | b'o' | b'u' => false,
+            b'y' => i == 0 || !self.is_consonant(i - 1),
+            _ => true,
+        }
+    }
+
+    /// Number of vowel-consonant sequences in the stem, the first `j` letters.
+    fn measure(&self) -> usize {
+        let mut i = 0;
+
+        while i < self.j && self.is_consonant(i) {
+            i += 1;
+        }
+
+        let mut count = 0;
+
+        while i < self.j {
+            while i < self.j && !self.is_consonant(i) {
+                i += 1;
+            }
+
+            if i >= self.j {
+                break;
+            }
+
+            count += 1;
+
+            while i < self.j && self.is_consonant(i) {
+                i += 1;
+            }
+        }
+
+        count
+    }
+
+    fn has_vowel(&self) -> bool {
+        (0..self.j).any(|i| !self.is_consonant(i))
+    }
+
+    fn is_double_consonant(&self, i: usize) -> bool {
+        i >= 1 && self.b[i] == self.b[i - 1] && self.is_consonant(i)
+    }
+
+    /// Consonant-vowel-consonant ending at `i`, where the last consonant is not w, x or y.
+    fn is_cvc(&self, i: usize) -> bool {
+        i >= 2
+            && self.is_consonant(i)
+            && !self.is_consonant(i - 1)
+            && self.is_consonant(i - 2)
+            && !matches!(self.b[i], b'w' | b'x' | b'y')
+    }
+
+    /// Whether the word ends with the suffix, setting the stem length `j` before it.
+    fn ends(&mut self, suffix: &str) -> bool {
+        let length = suffix.len();
+
+        if length > self.k + 1 || &self.b[self.k + 1 - length..=self.k] != suffix.as_bytes() {
+            return false;
+        }
+
+        self.j = self.k + 1 - length;
+        true
+    }
+
+    fn set_to(&mut self, suffix: &str) {
+        self.b.truncate(self.j);
+        self.b.extend_from_slice(suffix.as_bytes());
+        self.k = self.j + suffix.len() - 1;
+    }
+
+    fn replace(&mut self, suffix: &str) {
+        if self.measure() > 0 {
+            self.set_to(suffix);
+        }
+    }
+
+    /// Replaces the first matching suffix, when the stem has a positive measure.
+    fn replace_first(&mut self, rules: &[(&str, &str)]) {
+        if let Some((_, replacement)) = rules.iter().find(|(suffix, _)| self.ends(suffix)) {
+            self.replace(replacement);
+        }
+    }
+
+    /// Plurals and past participles.
+    fn step1ab(&mut self) {
+        if self.b[self.k] == b's' {
+            if self.ends("sses") {
+                self.k -= 2;
+            } else if self.ends("ies") {
+                self.set_to("i");
+            } else if self.b[self.k - 1] != b's' {
+                self.k -= 1;
+            }
+        }
+
+        if self.ends("eed") {
+            if self.measure() > 0 {
+                self.k -= 1;
+            }
+        } else if (self.ends("ed") || self.ends("ing")) && self.has_vowel() {
+            self.k = self.j - 1;
+            self.b.truncate(self.j);
+
+            if self.ends("at") {
+                self.set_to("ate");
+            } else if self.ends("bl") {
+                self.set_to("ble");
+            } else if self.ends("iz") {
+                self.set_to("ize");
+            } else if self.is_double_consonant(self.k) {
+                if !matches!(self.b[self.k], b'l' | b's' | b'z') {
+                    self.k -= 1;
+                }
+            } else {
+                self.j = self.k + 1;
+
+                if self.measure() == 1 && self.is_cvc(self.k) {
+                    self.set_to("e");
+                }
+            }
+        }
+    }
+
+    /// Terminal y to i when there is another vowel in the stem.
+    fn step1c(&mut self) {
+        if self.ends("y") && self.has_vowel() {
+            self.b[self.k] = b'i';
+        }
+    }
+
+    /// Double suffixes to single ones.
+    fn step2(&mut self) {
+        let rules: &[(&str, &str)] = match self.b[self.k - 1] {
+            b'a' => &[("ational", "ate"), ("tional", "tion")],
+            b'c' => &[("enci", "ence"), ("anci", "ance")],
+            b'e' => &[("izer", "ize")],
+            b'l' => &[
+                ("bli", "ble"),
+                ("alli", "al"),
+                ("entli", "ent"),
+                ("eli", "e"),
+                ("ousli", "ous"),
+            ],
+            b'o' => &[("ization", "ize"), ("ation", "ate"), ("ator", "ate")],
+            b's' => &[
+                ("alism", "al"),
+                ("iveness", "ive"),
+                ("fulness", "ful"),
+                ("ousness", "ous"),
+            ],
+            b't' => &[("aliti", "al"), ("iviti", "ive"), ("biliti", "ble")],
+            b'g' => &[("logi", "log")],
+            _ => &[],
+        };
+        self.replace_first(rules);
+    }
+
+    /// -ic-, -full, -ness and similar suffixes.
+    fn step3(&mut self) {
+        let rules: &[(&str, &str)] = match self.b[self.k] {
+            b'e' => &[("icate", "ic"), ("ative", ""), ("alize", "al")],
+            b'i' => &[("iciti", "ic")],
+            b'l' => &[("ical", "ic"), ("ful", "")],
+            b's' => &[("ness", "")],
+            _ => &[],
+        };
+        self.replace_first(rules);
+    }
+
+    /// Removes -ant, -ence and similar suffixes when the stem measure is above one.
+    fn step4(&mut self) {
+        let suffixes: &[&str] = match self.b[self.k - 1] {
+            b'a' => &["al"],
+            b'c' => &["ance", "ence"],
+            b'e' => &["er"],
+            b'i' => &["ic"],
+            b'l' => &["able", "ible"],
+            b'n' => &["ant", "ement", "ment", "ent"],
+            b'o' => &["ion", "ou"],
+            b's' => &["ism"],
+            b't' => &["ate", "iti"],
+            b'u' => &["ous"],
+            b'v' => &["ive"],
+            b'z' => &["ize"],
+            _ => &[],
+        };
+        let matched = suffixes.iter().find(|suffix| self.ends(suffix)).copied();
+        let matched = match matched {
+            Some("ion") if self.j == 0 || !matches!(self.b[self.j - 1], b's' | b't') => {
+                self.ends("ou")
+            }
+            Some(_) => true,
+            None => false,
+        };
+
+        if matched && self.measure() > 1 {
+            self.k = self.j - 1;
+        }
+    }
+
+    /// Removes a final -e and reduces a final -ll.
+    fn step5(&mut self) {
+        self.j = self.k + 1;
+
+        if self.b[self.k] == b'e' {
+            let measure = self.measure();
+
+            if measure > 1 || (measure == 1 && !self.is_cvc(self.k - 1)) {
+                self.k -= 1;
+            }
+        }
+
+        if self.b[self.k] == b'l' && self.is_double_consonant(self.k) && self.measure() > 1 {
+            self.k -= 1;
+        }
+    }
+}
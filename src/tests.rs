@@ -13,7 +13,10 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
 
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use stop_words::{get, LANGUAGE};
 
@@ -60,7 +63,7 @@ If you are passionate about Rust development and looking to kickstart your caree
 "#;
 
 fn get_cs_hashset() -> HashSet<String> {
-    HashSet::from_iter(vec!["c", "computer"].iter().map(|s| s.to_string()))
+    HashSet::from_iter(["c", "computer"].iter().map(|s| s.to_string()))
 }
 
 fn get_stop_words() -> Vec<String> {
@@ -91,12 +94,437 @@ fn is_percent_in_hashset(vector: &[String], hashset: &HashSet<String>, percent:
     percentage >= percent
 }
 
+#[cfg(feature = "yake")]
 fn contains_all(strings: &[String], substrings: &[&str]) -> bool {
     substrings
         .iter()
         .all(|substr| strings.contains(&substr.to_string()))
 }
 
+#[test]
+fn test_stemmer() {
+    let stem = |stemmer: stemmer::Stemmer, words: &[&str]| {
+        words
+            .iter()
+            .map(|word| stemmer.stem(word))
+            .collect::<Vec<String>>()
+    };
+    let words = [
+        "Developers",
+        "caresses",
+        "ponies",
+        "relational",
+        "hopping",
+        "generalizations",
+        "Ökonomie",
+    ];
+
+    assert_eq!(
+        stem(stemmer::Stemmer::Porter, &words),
+        [
+            "develop",
+            "caress",
+            "poni",
+            "relat",
+            "hop",
+            "gener",
+            "ökonomie"
+        ]
+    );
+    assert_eq!(
+        stem(stemmer::Stemmer::Plural, &words),
+        [
+            "developer",
+            "caresse",
+            "ponie",
+            "relational",
+            "hopping",
+            "generalization",
+            "ökonomie"
+        ]
+    );
+    assert_eq!(
+        stem(
+            stemmer::Stemmer::Custom(Arc::new(|word: &str| word.chars().take(4).collect())),
+            &words[..2]
+        ),
+        ["deve", "care"]
+    );
+
+    let lemmas = HashMap::from([("ponies", "pony"), ("caresses", "caress")]);
+    assert_eq!(
+        stem(
+            stemmer::Stemmer::Custom(Arc::new(move |word: &str| {
+                lemmas.get(word).unwrap_or(&word).to_string()
+            })),
+            &words[..3]
+        ),
+        ["developers", "caress", "pony"]
+    );
+}
+
+#[test]
+fn test_similarity() {
+    use similarity::*;
+
+    let levenshtein = Levenshtein::new("kitten", "sitting");
+    assert_eq!(levenshtein.distance(), 3);
+    assert!((levenshtein.ratio() - 4.0 / 7.0).abs() < 1e-6);
+    assert_eq!(Levenshtein::bounded_distance("kitten", "sitting", 2), None);
+    assert_eq!(
+        Levenshtein::bounded_distance("kitten", "sitting", 3),
+        Some(3)
+    );
+    assert_eq!(Levenshtein::new("", "").ratio(), 1.0);
+
+    let pairs = [
+        ("rust developer", "rust developers"),
+        ("kitten", "sitting"),
+        ("", "abc"),
+    ];
+    pairs.iter().for_each(|(a, b)| {
+        [0.0, 0.5, 0.9, 1.0].iter().for_each(|threshold| {
+            assert_eq!(
+                Levenshtein::is_ratio_at_least(a, b, *threshold),
+                Levenshtein::new(a, b).ratio() >= *threshold
+            );
+        })
+    });
+
+    assert_eq!(DamerauLevenshtein::new("ca", "abc").distance(), 2);
+    assert_eq!(Levenshtein::new("ca", "abc").distance(), 3);
+    assert_eq!(DamerauLevenshtein::new("rust", "rsut").distance(), 1);
+
+    assert!((JaroWinkler::new("martha", "marhta").ratio() - 0.9611).abs() < 1e-4);
+    assert!((JaroWinkler::new("dixon", "dicksonx").ratio() - 0.8133).abs() < 1e-4);
+    assert!((JaroWinkler::new("martha", "marhta").jaro() - 0.9444).abs() < 1e-4);
+
+    assert_eq!(NgramJaccard::new("night", "nacht", 2).ratio(), 1.0 / 7.0);
+    assert_eq!(NgramJaccard::new("ab", "ab", 3).ratio(), 1.0);
+    assert_eq!(NgramJaccard::new("", "", 2).ratio(), 1.0);
+
+    assert_eq!(SequenceMatcher::new("abcd", "bcde").ratio(), 0.75);
+}
+
+#[test]
+fn test_keyword_dedup() {
+    use keyword_dedup::{DedupStrategy, KeywordDedup};
+
+    let scores = [
+        ("rust developer", 5.0),
+        ("rust developers", 4.0),
+        ("junior rust developer", 3.5),
+        ("developing", 3.0),
+        ("rust", 2.0),
+        ("team", 1.0),
+    ]
+    .iter()
+    .map(|(keyword, score)| (keyword.to_string(), *score))
+    .collect::<HashMap<String, f32>>();
+
+    let no_similarity = KeywordDedup {
+        threshold: 1.1,
+        ..Default::default()
+    };
+    assert_eq!(no_similarity.get_ranked_strings(&scores, 10).len(), 6);
+    assert_eq!(
+        KeywordDedup::default().get_ranked_strings(&scores, 3),
+        ["rust developer", "junior rust developer", "developing"]
+    );
+    assert_eq!(
+        KeywordDedup {
+            stemmer: Some(stemmer::Stemmer::Porter),
+            subsumption: true,
+            ..no_similarity
+        }
+        .get_ranked_scores(&scores, 10),
+        [
+            ("rust developer".to_string(), 5.0),
+            ("junior rust developer".to_string(), 3.5),
+            ("team".to_string(), 1.0),
+        ]
+    );
+    let length_tolerance = 0;
+    assert_eq!(
+        KeywordDedup {
+            strategy: DedupStrategy::Custom(Arc::new(move |a: &str, b: &str| {
+                (a.len().abs_diff(b.len()) <= length_tolerance) as u8 as f32
+            })),
+            threshold: 1.0,
+            ..Default::default()
+        }
+        .get_ranked_strings(&scores, 10),
+        [
+            "rust developer",
+            "rust developers",
+            "junior rust developer",
+            "developing",
+            "rust"
+        ]
+    );
+}
+
+#[test]
+fn test_mmr() {
+    use mmr::{Mmr, MmrSimilarity};
+
+    let to_map = |scores: &[(&str, f32)]| {
+        scores
+            .iter()
+            .map(|(keyword, score)| (keyword.to_string(), *score))
+            .collect::<HashMap<String, f32>>()
+    };
+    let scores = to_map(&[
+        ("rust developer", 5.0),
+        ("rust developers", 4.8),
+        ("junior rust developer", 4.5),
+        ("team", 2.0),
+        ("code reviews", 1.0),
+    ]);
+
+    let relevance_only = Mmr {
+        lambda: 1.0,
+        ..Default::default()
+    };
+    assert_eq!(
+        relevance_only.get_ranked_scores(&scores, 5),
+        common::get_ranked_scores(&scores, 5)
+    );
+    assert_eq!(
+        Mmr::default().get_ranked_strings(&scores, 3),
+        ["rust developer", "junior rust developer", "team"]
+    );
+
+    let word_scores = to_map(&[
+        ("rust", 1.0),
+        ("developer", 1.0),
+        ("developers", 1.0),
+        ("junior", 0.5),
+        ("team", 1.0),
+    ]);
+    let tf_idf = Mmr {
+        similarity: MmrSimilarity::TfIdfCosine(&word_scores),
+        ..Default::default()
+    };
+    assert_eq!(
+        tf_idf.get_ranked_strings(&scores, 3),
+        ["rust developer", "rust developers", "team"]
+    );
+    assert_eq!(tf_idf.get_ranked_scores(&scores, 10).len(), 5);
+    assert!(tf_idf.get_ranked_scores(&HashMap::new(), 3).is_empty());
+
+    let cased_scores = to_map(&[
+        ("Rust developer", 5.0),
+        ("rust Developer", 4.9),
+        ("team", 1.0),
+    ]);
+    assert_eq!(
+        tf_idf.get_ranked_strings(&cased_scores, 2),
+        ["Rust developer", "team"]
+    );
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_mmr_co_occurrence() {
+    let documents = vec![
+        "the cat eats fish".to_string(),
+        "the dog eats meat".to_string(),
+        "a car drives fast".to_string(),
+    ];
+    let words = documents
+        .iter()
+        .flat_map(|d| d.split_whitespace().map(|w| w.to_string()))
+        .collect::<Vec<String>>();
+    let co_occurrence = co_occurrence::CoOccurrence::new(&documents, &words, 1);
+    let scores = [("cat", 3.0), ("dog", 2.0), ("car", 1.0)]
+        .iter()
+        .map(|(keyword, score)| (keyword.to_string(), *score))
+        .collect::<HashMap<String, f32>>();
+    let mmr = mmr::Mmr {
+        lambda: 0.5,
+        similarity: mmr::MmrSimilarity::CoOccurrence(&co_occurrence),
+    };
+
+    assert_eq!(mmr.get_ranked_strings(&scores, 3), ["cat", "car", "dog"]);
+
+    let dense = co_occurrence.get_word_vector("eats").unwrap();
+    let sparse = co_occurrence.get_sparse_word_vector("eats").unwrap();
+    assert_eq!(
+        sparse,
+        dense
+            .into_iter()
+            .enumerate()
+            .filter(|(_, value)| *value != 0.0)
+            .collect::<Vec<(usize, f32)>>()
+    );
+}
+
+#[test]
+fn test_score_normalization() {
+    use normalization::ScoreNormalization;
+
+    let scores = [("a", 4.0), ("b", 2.0), ("c", 2.0), ("d", 0.0)]
+        .iter()
+        .map(|(keyword, score)| (keyword.to_string(), *score))
+        .collect::<HashMap<String, f32>>();
+    let normalize = |normalization: ScoreNormalization| {
+        let normalized = normalization.normalize(&scores);
+        ["a", "b", "c", "d"].map(|keyword| normalized[keyword])
+    };
+
+    assert_eq!(normalize(ScoreNormalization::MinMax), [1.0, 0.5, 0.5, 0.0]);
+    assert_eq!(
+        normalize(ScoreNormalization::ZScore),
+        [2.0 / 2.0_f32.sqrt(), 0.0, 0.0, -2.0 / 2.0_f32.sqrt()]
+    );
+    assert_eq!(
+        normalize(ScoreNormalization::RankPercentile),
+        [1.0, 0.75, 0.75, 0.25]
+    );
+    let softmax = normalize(ScoreNormalization::Softmax);
+    assert!((softmax.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+    assert!(softmax[0] > softmax[1] && softmax[1] == softmax[2] && softmax[2] > softmax[3]);
+    assert!(ScoreNormalization::MinMax
+        .normalize(&HashMap::new())
+        .is_empty());
+
+    let rake_struct = rake::Rake::new(rake::RakeParams::WithDefaults(TEXT, &get_stop_words()))
+        .with_normalization(ScoreNormalization::MinMax);
+    let phrases = rake_struct.get_phrases_above(0.5);
+    assert_eq!(phrases[0].1, 1.0);
+    assert!(phrases.iter().all(|(_, score)| *score >= 0.5));
+    assert_eq!(
+        phrases,
+        rake_struct.get_ranked_phrases_scores(phrases.len())
+    );
+    assert!(rake_struct.get_ranked_phrases_scores(phrases.len() + 1)[phrases.len()].1 < 0.5);
+
+    let tf_idf = tf_idf::TfIdf::new(tf_idf::TfIdfParams::TextBlock(
+        TEXT,
+        &get_stop_words(),
+        None,
+        tf_idf::TextSplit::Sentences,
+    ))
+    .with_normalization(ScoreNormalization::RankPercentile);
+    assert_eq!(tf_idf.get_keywords_above(1.0).len(), 1);
+    assert_eq!(
+        tf_idf.get_keywords_above(0.0).len(),
+        tf_idf.get_word_scores_map().len()
+    );
+}
+
+#[test]
+fn test_ensemble() {
+    use ensemble::{Ensemble, EnsembleAlgorithm, EnsembleOptions, EnsembleParams, RankFusion};
+
+    let stop_words = get_stop_words();
+    let ensemble = Ensemble::new(EnsembleParams::WithDefaults(TEXT, &stop_words));
+    let top = ensemble.get_ranked_keywords(10);
+    assert_eq!(top.len(), 10);
+    top.iter().for_each(|keyword| {
+        assert_eq!(keyword, &keyword.to_lowercase());
+        assert!(keyword.split_whitespace().count() <= 3);
+    });
+    assert!(ensemble
+        .get_algorithm_scores_map(EnsembleAlgorithm::TfIdf)
+        .is_some());
+    assert_eq!(
+        ensemble.get_keyword_score(&top[0].to_uppercase()),
+        ensemble.get_keyword_scores_map()[&top[0]]
+    );
+
+    let rake_struct = rake::Rake::new(rake::RakeParams::WithDefaultsAndPhraseLength(
+        TEXT,
+        &stop_words,
+        Some(3),
+    ));
+    let rake_only = Ensemble::new(EnsembleParams::WithAlgorithms(
+        TEXT,
+        &stop_words,
+        &[(EnsembleAlgorithm::Rake, 1.0)],
+        RankFusion::default(),
+    ));
+    assert_eq!(
+        rake_only.get_ranked_keywords(10),
+        rake_struct.get_ranked_phrases(10)
+    );
+    assert!(rake_only
+        .get_algorithm_scores_map(EnsembleAlgorithm::TextRank)
+        .is_none());
+
+    let weights = [
+        (EnsembleAlgorithm::Rake, 2.0),
+        (EnsembleAlgorithm::TextRank, 1.0),
+        (EnsembleAlgorithm::TfIdf, 1.0),
+    ];
+    [RankFusion::Borda, RankFusion::WeightedAverage]
+        .iter()
+        .for_each(|fusion| {
+            let fused = Ensemble::new(EnsembleParams::WithAlgorithms(
+                TEXT,
+                &stop_words,
+                &weights,
+                *fusion,
+            ));
+            let max_score = match fusion {
+                RankFusion::WeightedAverage => 1.0,
+                _ => 4.0,
+            };
+            fused
+                .get_ranked_keyword_scores(usize::MAX)
+                .iter()
+                .for_each(|(_, score)| assert!(*score >= 0.0 && *score <= max_score));
+        });
+
+    let ignored_text_rank = Ensemble::new(EnsembleParams::WithAlgorithms(
+        TEXT,
+        &stop_words,
+        &[
+            (EnsembleAlgorithm::Rake, 1.0),
+            (EnsembleAlgorithm::TextRank, 0.0),
+        ],
+        RankFusion::Borda,
+    ));
+    assert_eq!(
+        ignored_text_rank.get_ranked_keywords(5),
+        rake_struct.get_ranked_phrases(5)
+    );
+    assert!(ignored_text_rank
+        .get_algorithm_scores_map(EnsembleAlgorithm::TextRank)
+        .is_none());
+
+    let text_rank_options = text_rank::TextRankOptions {
+        window_size: 4,
+        ..text_rank::TextRankOptions::default()
+    };
+    let text_rank_struct = text_rank::TextRank::new(text_rank::TextRankParams::WithOptions(
+        TEXT,
+        &stop_words,
+        None,
+        Some(3),
+        text_rank_options,
+    ));
+    // The update is only needed when the yake feature adds its fields.
+    #[allow(clippy::needless_update)]
+    let text_rank_only = Ensemble::new(EnsembleParams::WithOptions(
+        TEXT,
+        &stop_words,
+        None,
+        Some(3),
+        &[(EnsembleAlgorithm::TextRank, 1.0)],
+        RankFusion::default(),
+        EnsembleOptions {
+            text_rank: text_rank_options,
+            ..EnsembleOptions::default()
+        },
+    ));
+    assert_eq!(
+        text_rank_only.get_ranked_keywords(10),
+        text_rank_struct.get_ranked_phrases(10)
+    );
+}
+
 #[test]
 fn test_tokenize() {
     let tokenizer = tokenizer::Tokenizer::new(TEXT, &get_stop_words(), None);
@@ -436,7 +864,7 @@ fn test_tf_idf() {
 fn test_co_occurrence() {
     let documents =
         tokenizer::Tokenizer::new(TEXT, &get_stop_words(), None).split_into_paragraphs();
-    let word_vec = vec![
+    let word_vec = [
         "rust",
         "development",
         "environment",
@@ -470,6 +898,517 @@ fn test_co_occurrence() {
     );
 }
 
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_sparse_matrix() {
+    let documents =
+        tokenizer::Tokenizer::new(TEXT, &get_stop_words(), None).split_into_paragraphs();
+    let word_vec = tokenizer::Tokenizer::new(TEXT, &get_stop_words(), None)
+        .split_into_words()
+        .into_iter()
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect::<Vec<String>>();
+    let co_occurrence = co_occurrence::CoOccurrence::new(&documents, &word_vec, 2);
+    let matrix = co_occurrence.get_sparse_matrix();
+    assert_eq!(matrix.size(), word_vec.len());
+    assert!(matrix.non_zero_count() < word_vec.len() * word_vec.len() / 10);
+
+    let dense_matrix = co_occurrence.get_dense_matrix();
+    #[allow(deprecated)]
+    {
+        assert_eq!(co_occurrence.get_matrix(), &dense_matrix);
+        assert!(std::ptr::eq(
+            co_occurrence.get_matrix(),
+            co_occurrence.get_matrix()
+        ));
+    }
+    for word in &word_vec {
+        let label = co_occurrence.get_label(word).unwrap();
+        let row = co_occurrence.get_matrix_row(word).unwrap();
+        assert_eq!(row, dense_matrix[label]);
+
+        for (other, value) in co_occurrence.get_relations(word).unwrap() {
+            assert!(value > 0.0);
+            assert_eq!(co_occurrence.get_relation(word, &other), Some(value));
+            assert_eq!(row[co_occurrence.get_label(&other).unwrap()], value);
+        }
+    }
+    assert_eq!(co_occurrence.get_relation("rust", "unknown"), None);
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_association_measures() {
+    let documents = ["a b", "a b", "a c"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+    let words = ["a", "b", "c"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+    let get_co_occurrence = |measure| {
+        co_occurrence::CoOccurrence::new(&documents, &words, 1).with_association_measure(measure)
+    };
+    let assert_close = |value: Option<f32>, expected: f32| {
+        assert!((value.unwrap() - expected).abs() < 1e-6);
+    };
+
+    let normalized = get_co_occurrence(co_occurrence::AssociationMeasure::Normalized);
+    assert_eq!(
+        normalized.get_top_pairs(5),
+        [
+            ("a".to_string(), "b".to_string(), 1.0),
+            ("a".to_string(), "c".to_string(), 0.5)
+        ]
+    );
+
+    let pmi = get_co_occurrence(co_occurrence::AssociationMeasure::Pmi);
+    assert_close(pmi.get_association("a", "b"), 2.0_f32.ln());
+    assert_eq!(pmi.get_association("b", "c"), Some(f32::NEG_INFINITY));
+
+    let npmi = get_co_occurrence(co_occurrence::AssociationMeasure::Npmi);
+    assert_close(npmi.get_association("a", "b"), 2.0_f32.ln() / 3.0_f32.ln());
+
+    let ppmi = get_co_occurrence(co_occurrence::AssociationMeasure::Ppmi);
+    assert_close(ppmi.get_association("a", "c"), 2.0_f32.ln());
+    assert_eq!(ppmi.get_association("b", "c"), Some(0.0));
+
+    let dice = get_co_occurrence(co_occurrence::AssociationMeasure::Dice);
+    assert_close(dice.get_association("a", "b"), 0.8);
+
+    let jaccard = get_co_occurrence(co_occurrence::AssociationMeasure::Jaccard);
+    assert_close(jaccard.get_association("a", "b"), 2.0 / 3.0);
+
+    let log_likelihood = get_co_occurrence(co_occurrence::AssociationMeasure::LogLikelihood);
+    let associations = log_likelihood.get_associations("a").unwrap();
+    assert_eq!(associations.len(), 2);
+    assert!(associations.iter().all(|(_, score)| *score > 0.0));
+    assert_eq!(log_likelihood.get_top_pairs(1).len(), 1);
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_association_window() {
+    let documents = ["a b c", "a c"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+    let words = ["a", "b", "c"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+    let get_co_occurrence = |measure| {
+        co_occurrence::CoOccurrence::new(&documents, &words, 2).with_association_measure(measure)
+    };
+    let assert_close = |value: Option<f32>, expected: f32| {
+        assert!((value.unwrap() - expected).abs() < 1e-5);
+    };
+
+    // c(a, b) = 1, c(a, c) = 2, f(a) = 2, f(b) = 1, f(c) = 2, out of 5 tokens.
+    let dice = get_co_occurrence(co_occurrence::AssociationMeasure::Dice);
+    assert_close(dice.get_association("a", "c"), 1.0);
+    assert_close(dice.get_association("a", "b"), 2.0 / 3.0);
+
+    let jaccard = get_co_occurrence(co_occurrence::AssociationMeasure::Jaccard);
+    assert_close(jaccard.get_association("a", "b"), 0.5);
+    assert_close(jaccard.get_association("b", "c"), 0.5);
+
+    // Contingency table of a and b: [[1, 1], [0, 3]].
+    let log_likelihood = get_co_occurrence(co_occurrence::AssociationMeasure::LogLikelihood);
+    assert_close(
+        log_likelihood.get_association("a", "b"),
+        2.0 * (2.5_f32.ln() + 0.625_f32.ln() + 3.0 * 1.25_f32.ln()),
+    );
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_from_text() {
+    let stop_words = get_stop_words();
+    let co_occurrence =
+        co_occurrence::CoOccurrence::from_params(co_occurrence::CoOccurrenceParams::TextBlock(
+            TEXT,
+            &stop_words,
+            None,
+            co_occurrence::CoOccurrenceOptions {
+                window_size: 3,
+                window_boundary: co_occurrence::WindowBoundary::Sentences,
+                min_count: 2,
+                max_vocabulary: Some(10),
+                ..co_occurrence::CoOccurrenceOptions::default()
+            },
+        ));
+    let labels = co_occurrence.get_labels();
+    assert_eq!(labels.len(), 10);
+    assert_eq!(co_occurrence.get_label("rust"), Some(0));
+    assert!(co_occurrence.get_label("kickstart").is_none());
+    assert!(co_occurrence.get_label("skills").is_none());
+    assert!(co_occurrence.get_relation("rust", "development").unwrap() > 0.0);
+
+    let documents = TEXT
+        .split("\n\n")
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+    let sentence_bounded = co_occurrence::CoOccurrence::from_params(
+        co_occurrence::CoOccurrenceParams::UnprocessedDocuments(
+            &documents,
+            &stop_words,
+            None,
+            co_occurrence::CoOccurrenceOptions {
+                window_boundary: co_occurrence::WindowBoundary::Sentences,
+                ..co_occurrence::CoOccurrenceOptions::default()
+            },
+        ),
+    );
+    let document_bounded = co_occurrence::CoOccurrence::from_params(
+        co_occurrence::CoOccurrenceParams::UnprocessedDocuments(
+            &documents,
+            &stop_words,
+            None,
+            co_occurrence::CoOccurrenceOptions::default(),
+        ),
+    );
+    assert_eq!(
+        sentence_bounded.get_labels().len(),
+        document_bounded.get_labels().len()
+    );
+    assert_eq!(sentence_bounded.get_relation("team", "ideal"), Some(0.0));
+    assert!(document_bounded.get_relation("team", "ideal").unwrap() > 0.0);
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_distance_and_direction() {
+    let documents = vec![
+        "machine learning models".to_string(),
+        "machine learning rocks".to_string(),
+    ];
+    let words = vec![
+        "machine".to_string(),
+        "learning".to_string(),
+        "models".to_string(),
+        "rocks".to_string(),
+    ];
+    let inverse = co_occurrence::CoOccurrence::with_options(
+        &documents,
+        &words,
+        &co_occurrence::CoOccurrenceOptions {
+            distance_decay: co_occurrence::DistanceDecay::Inverse,
+            directional: true,
+            ..co_occurrence::CoOccurrenceOptions::default()
+        },
+    );
+    let counts = inverse.get_count_matrix();
+    assert_eq!(counts.get(0, 1), 2.0);
+    assert_eq!(counts.get(0, 2), 0.5);
+    assert_eq!(counts.get(2, 0), 0.5);
+    assert_eq!(
+        inverse.get_directed_relation("machine", "learning"),
+        Some(2.0)
+    );
+    assert_eq!(
+        inverse.get_directed_relation("learning", "machine"),
+        Some(0.0)
+    );
+
+    let left = inverse.get_left_context_matrix().unwrap();
+    let right = inverse.get_right_context_matrix().unwrap();
+    assert_eq!(left, &right.transpose());
+    (0..words.len()).for_each(|i| {
+        (0..words.len()).for_each(|j| {
+            assert_eq!(counts.get(i, j), left.get(i, j) + right.get(i, j));
+        })
+    });
+
+    let linear = co_occurrence::CoOccurrence::with_options(
+        &documents,
+        &words,
+        &co_occurrence::CoOccurrenceOptions {
+            distance_decay: co_occurrence::DistanceDecay::Linear,
+            ..co_occurrence::CoOccurrenceOptions::default()
+        },
+    );
+    assert_eq!(linear.get_count_matrix().get(0, 2), 0.5);
+    assert!(linear.get_right_context_matrix().is_none());
+    assert!(linear
+        .get_directed_relation("machine", "learning")
+        .is_none());
+
+    let exponential = co_occurrence::DistanceDecay::Exponential(std::f32::consts::LN_2);
+    assert_eq!(exponential.weight(1, 2), 1.0);
+    assert!((exponential.weight(2, 2) - 0.5).abs() < 1e-6);
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_word_similarity() {
+    let documents = vec![
+        "the cat eats fish".to_string(),
+        "the dog eats meat".to_string(),
+        "a car drives fast".to_string(),
+    ];
+    let words = documents
+        .iter()
+        .flat_map(|d| d.split_whitespace().map(|w| w.to_string()))
+        .collect::<Vec<String>>();
+    let co_occurrence = co_occurrence::CoOccurrence::new(&documents, &words, 1);
+    assert_eq!(co_occurrence.similarity("cat", "dog"), Some(1.0));
+    assert_eq!(co_occurrence.similarity("cat", "car"), Some(0.0));
+    assert!(co_occurrence.similarity("cat", "bird").is_none());
+    let most_similar = co_occurrence.most_similar("cat", 2).unwrap();
+    assert_eq!(most_similar[0], ("dog".to_string(), 1.0));
+    assert_eq!(most_similar.len(), 2);
+
+    let ppmi = co_occurrence::CoOccurrence::new(&documents, &words, 1)
+        .with_word_vectors(co_occurrence::VectorWeighting::Ppmi, None);
+    assert_eq!(ppmi.most_similar("cat", 1).unwrap()[0].0, "dog");
+
+    let full_rank = co_occurrence::CoOccurrence::new(&documents, &words, 1)
+        .with_word_vectors(co_occurrence::VectorWeighting::Counts, Some(words.len()));
+    words.iter().for_each(|word| {
+        let similarity = full_rank.similarity("eats", word).unwrap();
+        let expected = co_occurrence.similarity("eats", word).unwrap();
+        assert!((similarity - expected).abs() < 1e-4);
+    });
+
+    let reduced = co_occurrence::CoOccurrence::new(&documents, &words, 1)
+        .with_word_vectors(co_occurrence::VectorWeighting::Ppmi, Some(3));
+    assert_eq!(reduced.get_word_vector("cat").unwrap().len(), 3);
+    assert_eq!(reduced.most_similar("cat", 1).unwrap()[0].0, "dog");
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_clustering() {
+    let documents = vec![
+        "rust cargo compiler rust borrow checker cargo".to_string(),
+        "compiler borrow checker rust cargo".to_string(),
+        "garden flowers soil garden seeds".to_string(),
+        "seeds soil flowers garden".to_string(),
+        "cargo garden".to_string(),
+    ];
+    let words = vec![
+        "rust".to_string(),
+        "cargo".to_string(),
+        "compiler".to_string(),
+        "borrow".to_string(),
+        "checker".to_string(),
+        "garden".to_string(),
+        "flowers".to_string(),
+        "soil".to_string(),
+        "seeds".to_string(),
+        "unused".to_string(),
+    ];
+    let co_occurrence = co_occurrence::CoOccurrence::new(&documents, &words, 2);
+
+    [
+        co_occurrence::CommunityDetection::Louvain,
+        co_occurrence::CommunityDetection::LabelPropagation,
+    ]
+    .into_iter()
+    .for_each(|algorithm| {
+        let options = co_occurrence::ClusteringOptions {
+            algorithm,
+            label_size: 2,
+            ..co_occurrence::ClusteringOptions::default()
+        };
+        let clusters = co_occurrence.get_clusters(&options);
+        assert_eq!(clusters, co_occurrence.get_clusters(&options));
+        assert_eq!(clusters.len(), 2);
+
+        let mut programming = clusters[0]
+            .words
+            .iter()
+            .map(|(w, _)| w.as_str())
+            .collect::<Vec<&str>>();
+        programming.sort_unstable();
+        assert_eq!(
+            programming,
+            vec!["borrow", "cargo", "checker", "compiler", "rust"]
+        );
+        assert_eq!(clusters[1].words.len(), 4);
+        assert_eq!(clusters[0].label.split(", ").count(), 2);
+        assert!(clusters[0].words[0].1 >= clusters[0].words[1].1);
+    });
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_builder() {
+    let stop_words = get_stop_words();
+    let paragraphs = TEXT
+        .split("\n\n")
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+    let options = co_occurrence::CoOccurrenceOptions {
+        window_boundary: co_occurrence::WindowBoundary::Sentences,
+        directional: true,
+        min_count: 2,
+        ..co_occurrence::CoOccurrenceOptions::default()
+    };
+    let expected = co_occurrence::CoOccurrence::from_params(
+        co_occurrence::CoOccurrenceParams::UnprocessedDocuments(
+            &paragraphs,
+            &stop_words,
+            None,
+            options,
+        ),
+    );
+
+    let (first_half, second_half) = paragraphs.split_at(paragraphs.len() / 2);
+    let mut builder = co_occurrence::CoOccurrenceBuilder::new(options);
+    first_half.iter().for_each(|paragraph| {
+        builder.add_text(paragraph, &stop_words, None);
+    });
+    let mut other = co_occurrence::CoOccurrenceBuilder::new(options);
+    second_half.iter().for_each(|paragraph| {
+        other.add_text(paragraph, &stop_words, None);
+    });
+    let vocabulary_size = builder.get_vocabulary_size();
+    builder.merge(other);
+    assert!(builder.get_vocabulary_size() > vocabulary_size);
+
+    let co_occurrence = builder.finalize();
+    assert_eq!(co_occurrence.get_labels(), expected.get_labels());
+    assert_eq!(
+        co_occurrence.get_count_matrix(),
+        expected.get_count_matrix()
+    );
+    assert_eq!(
+        co_occurrence.get_right_context_matrix(),
+        expected.get_right_context_matrix()
+    );
+    assert_eq!(co_occurrence.get_graph(0.0), expected.get_graph(0.0));
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+#[should_panic(expected = "cannot merge co-occurrence builders with different counting options")]
+fn test_co_occurrence_builder_merge_options() {
+    let mut builder = co_occurrence::CoOccurrenceBuilder::new(Default::default());
+    builder.merge(co_occurrence::CoOccurrenceBuilder::new(
+        co_occurrence::CoOccurrenceOptions {
+            directional: true,
+            ..Default::default()
+        },
+    ));
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_phrases() {
+    let text = "Machine learning and deep learning. Machine learning models use deep learning.\n\
+        Natural language processing is fun.";
+    let phrases = vec![
+        "machine learning".to_string(),
+        "Deep Learning".to_string(),
+        "natural language processing".to_string(),
+        "learning".to_string(),
+        "models".to_string(),
+    ];
+    let co_occurrence = co_occurrence::CoOccurrence::from_phrases(
+        co_occurrence::PhraseCoOccurrenceParams::PhraseList(
+            text,
+            &phrases,
+            None,
+            co_occurrence::CoOccurrenceOptions {
+                window_size: usize::MAX,
+                window_boundary: co_occurrence::WindowBoundary::Sentences,
+                ..co_occurrence::CoOccurrenceOptions::default()
+            },
+        ),
+    );
+    assert_eq!(co_occurrence.get_label("machine learning"), Some(1));
+    assert_eq!(co_occurrence.get_label("deep learning"), Some(0));
+    assert!(co_occurrence.get_label("learning").is_none());
+    assert_eq!(
+        co_occurrence.get_relation("machine learning", "deep learning"),
+        Some(1.0)
+    );
+    assert_eq!(
+        co_occurrence.get_relation("deep learning", "natural language processing"),
+        Some(0.0)
+    );
+    assert!(
+        co_occurrence
+            .get_relation("models", "deep learning")
+            .unwrap()
+            > 0.0
+    );
+
+    let stop_words = get_stop_words();
+    let tokenizer_phrases = co_occurrence::CoOccurrence::from_phrases(
+        co_occurrence::PhraseCoOccurrenceParams::TokenizerPhrases(
+            TEXT,
+            &stop_words,
+            None,
+            None,
+            co_occurrence::CoOccurrenceOptions::default(),
+        ),
+    );
+    assert!(tokenizer_phrases
+        .get_labels()
+        .keys()
+        .any(|phrase| phrase.contains(' ')));
+    assert!(!tokenizer_phrases.get_top_pairs(1).is_empty());
+}
+
+#[cfg(feature = "co_occurrence")]
+#[test]
+fn test_co_occurrence_graph_export() {
+    let documents = vec!["machine learning \"models\" machine learning".to_string()];
+    let words = vec![
+        "machine".to_string(),
+        "learning".to_string(),
+        "\"models\"".to_string(),
+    ];
+    let co_occurrence = co_occurrence::CoOccurrence::new(&documents, &words, 1);
+    let graph = co_occurrence.get_graph(0.0);
+    assert!(!graph.is_directed());
+    assert_eq!(graph.get_nodes().len(), 3);
+    assert_eq!(graph.get_nodes()[2].id, "machine");
+    assert_eq!(graph.get_nodes()[2].frequency, 2);
+    assert_eq!(graph.get_edges().len(), 3);
+    assert_eq!(co_occurrence.get_graph(0.75).get_edges().len(), 1);
+
+    let dot = graph.export(graph_export::GraphFormat::Dot);
+    assert!(dot.starts_with("graph {"));
+    assert!(dot.contains("\"learning\" -- \"\\\"models\\\"\" [weight=0.5];"));
+    let graphml = graph.export(graph_export::GraphFormat::GraphMl);
+    assert!(graphml.contains("edgedefault=\"undirected\""));
+    assert!(graphml.contains("<node id=\"&quot;models&quot;\">"));
+    let json = graph.export(graph_export::GraphFormat::Json);
+    assert!(json.starts_with("{\"directed\":false"));
+    assert!(json.contains("{\"source\":\"machine\",\"target\":\"learning\",\"weight\":1}"));
+
+    let non_finite = graph_export::WordGraph::new(
+        true,
+        vec![graph_export::GraphNode {
+            id: "nan".to_string(),
+            score: f32::NAN,
+            frequency: 1,
+        }],
+        vec![graph_export::GraphEdge {
+            source: "nan".to_string(),
+            target: "nan".to_string(),
+            weight: f32::INFINITY,
+        }],
+    );
+    assert!(non_finite
+        .to_dot()
+        .contains("\"nan\" [score=\"NaN\", frequency=1];"));
+    assert!(non_finite.to_dot().contains("[weight=\"inf\"];"));
+    assert!(non_finite
+        .to_graphml()
+        .contains("<data key=\"weight\">INF</data>"));
+    assert_eq!(
+        non_finite.to_json(),
+        "{\"directed\":true,\"multigraph\":false,\"graph\":{},\"nodes\":[{\"id\":\"nan\",\"score\":null,\"frequency\":1}],\"links\":[{\"source\":\"nan\",\"target\":\"nan\",\"weight\":null}]}"
+    );
+}
+
 #[test]
 fn test_rake() {
     let rake_result = [
@@ -504,6 +1443,30 @@ fn test_rake() {
     }
 }
 
+#[test]
+fn test_rake_dedup() {
+    let rake_struct = rake::Rake::new(rake::RakeParams::WithDefaults(TEXT, &get_stop_words()));
+    let dedup = keyword_dedup::KeywordDedup {
+        stemmer: Some(stemmer::Stemmer::Porter),
+        subsumption: true,
+        ..Default::default()
+    };
+    let phrases = rake_struct.get_deduplicated_phrases(20, &dedup);
+
+    assert_eq!(phrases.len(), 20);
+    assert_eq!(phrases[0], rake_struct.get_ranked_phrases(1)[0]);
+    phrases.iter().enumerate().for_each(|(i, phrase)| {
+        phrases[..i].iter().for_each(|other| {
+            assert!(!format!(" {} ", other).contains(&format!(" {} ", phrase)));
+            assert!(similarity::Levenshtein::new(other, phrase).ratio() < 0.85);
+        })
+    });
+    assert_eq!(
+        rake_struct.get_deduplicated_phrase_scores(20, &dedup)[0].1,
+        rake_struct.get_phrase_score(&phrases[0])
+    );
+}
+
 #[test]
 fn test_text_rank() {
     let expected_words = [
@@ -552,21 +1515,211 @@ fn test_text_rank() {
     }
 }
 
+#[test]
+fn test_text_rank_diagnostics() {
+    let text_rank = text_rank::TextRank::new(text_rank::TextRankParams::WithDefaults(
+        TEXT,
+        &get_stop_words(),
+    ));
+    let diagnostics = text_rank.get_diagnostics();
+    assert!(diagnostics.converged);
+    assert!(diagnostics.iterations > 0);
+    assert!(diagnostics.delta < 0.00005);
+
+    let capped_text_rank = text_rank::TextRank::new(text_rank::TextRankParams::WithOptions(
+        TEXT,
+        &get_stop_words(),
+        None,
+        None,
+        text_rank::TextRankOptions {
+            tolerance: 0.0,
+            max_iterations: 10,
+            convergence: text_rank::Convergence::L1Delta,
+            ..text_rank::TextRankOptions::default()
+        },
+    ));
+    let capped_diagnostics = capped_text_rank.get_diagnostics();
+    assert!(!capped_diagnostics.converged);
+    assert_eq!(capped_diagnostics.iterations, 10);
+    assert!(!capped_text_rank.get_ranked_words(10).is_empty());
+}
+
+#[test]
+fn test_text_rank_windows() {
+    let stop_words = get_stop_words();
+    let text = "Alpha beta. Gamma delta.";
+    let unbounded =
+        text_rank::TextRank::new(text_rank::TextRankParams::WithDefaults(text, &stop_words));
+    assert!(unbounded.get_word_score("beta") > unbounded.get_word_score("alpha"));
+
+    let bounded = text_rank::TextRank::new(text_rank::TextRankParams::WithOptions(
+        text,
+        &stop_words,
+        None,
+        None,
+        text_rank::TextRankOptions {
+            sentence_bounded: true,
+            ..text_rank::TextRankOptions::default()
+        },
+    ));
+    assert_eq!(
+        bounded.get_word_score("beta"),
+        bounded.get_word_score("alpha")
+    );
+    assert_eq!(
+        bounded.get_word_score("beta"),
+        bounded.get_word_score("gamma")
+    );
+
+    let gap_text = "Alpha and the beta gamma";
+    let adjacent = text_rank::TextRank::new(text_rank::TextRankParams::WithDefaults(
+        gap_text,
+        &stop_words,
+    ));
+    assert!(adjacent.get_word_score("alpha") > 0.0);
+
+    let gapped = text_rank::TextRank::new(text_rank::TextRankParams::WithOptions(
+        gap_text,
+        &stop_words,
+        None,
+        None,
+        text_rank::TextRankOptions {
+            count_stop_words: true,
+            ..text_rank::TextRankOptions::default()
+        },
+    ));
+    assert_eq!(gapped.get_word_score("alpha"), 0.0);
+    assert!(gapped.get_word_score("beta") > 0.0);
+}
+
+#[test]
+fn test_text_rank_graph_variants() {
+    let stop_words = get_stop_words();
+    let text = "Alpha beta gamma";
+    let get_text_rank = |edge_direction, edge_weighting| {
+        text_rank::TextRank::new(text_rank::TextRankParams::WithOptions(
+            text,
+            &stop_words,
+            None,
+            None,
+            text_rank::TextRankOptions {
+                edge_direction,
+                edge_weighting,
+                ..text_rank::TextRankOptions::default()
+            },
+        ))
+    };
+
+    let forward = get_text_rank(
+        text_rank::EdgeDirection::Forward,
+        text_rank::EdgeWeighting::Count,
+    );
+    assert_eq!(forward.get_ranked_words(3), ["gamma", "beta", "alpha"]);
+
+    let backward = get_text_rank(
+        text_rank::EdgeDirection::Backward,
+        text_rank::EdgeWeighting::Count,
+    );
+    assert_eq!(backward.get_ranked_words(3), ["alpha", "beta", "gamma"]);
+
+    let unit = get_text_rank(
+        text_rank::EdgeDirection::Undirected,
+        text_rank::EdgeWeighting::Count,
+    );
+    assert_eq!(unit.get_word_score("alpha"), unit.get_word_score("beta"));
+
+    let weighted = get_text_rank(
+        text_rank::EdgeDirection::Undirected,
+        text_rank::EdgeWeighting::InverseDistance,
+    );
+    assert!(weighted.get_word_score("beta") > weighted.get_word_score("alpha"));
+}
+
+#[test]
+fn test_text_rank_graph_export() {
+    let stop_words = get_stop_words();
+    let text_rank = text_rank::TextRank::new(text_rank::TextRankParams::WithOptions(
+        TEXT,
+        &stop_words,
+        None,
+        None,
+        text_rank::TextRankOptions {
+            edge_direction: text_rank::EdgeDirection::Forward,
+            keep_graph: true,
+            ..text_rank::TextRankOptions::default()
+        },
+    ));
+    let graph = text_rank.get_graph(0.0).unwrap();
+    assert!(graph.is_directed());
+    let rust = graph
+        .get_nodes()
+        .iter()
+        .find(|node| node.id == "rust")
+        .unwrap();
+    assert_eq!(rust.score, text_rank.get_word_score("rust"));
+    assert!(rust.frequency > 1);
+    assert!(graph.get_edges().iter().all(|edge| edge.weight >= 1.0));
+    assert!(text_rank.get_graph(2.0).unwrap().get_edges().len() < graph.get_edges().len());
+    assert!(graph
+        .export(graph_export::GraphFormat::Dot)
+        .starts_with("digraph {"));
+    assert!(graph.to_graphml().contains("edgedefault=\"directed\""));
+    assert!(graph.to_json().starts_with("{\"directed\":true"));
+
+    let without_graph =
+        text_rank::TextRank::new(text_rank::TextRankParams::WithDefaults(TEXT, &stop_words));
+    assert!(without_graph.get_graph(0.0).is_none());
+}
+
+#[test]
+fn test_text_rank_summarizer() {
+    let summarizer = text_rank::Summarizer::new(text_rank::SummarizerParams::WithDefaults(
+        TEXT,
+        &get_stop_words(),
+    ));
+    let summary = summarizer.get_summary(3);
+    assert_eq!(summary.len(), 3);
+    assert!(summary.windows(2).all(|w| w[0].start < w[1].start));
+
+    for sentence in summarizer.get_sentences() {
+        assert_eq!(&TEXT[sentence.start..sentence.end], sentence.sentence);
+    }
+
+    let ranked = summarizer.get_ranked_sentences(3);
+    assert!(ranked.windows(2).all(|w| w[0].score >= w[1].score));
+    assert!(ranked.iter().all(|s| summary.contains(s)));
+
+    let lex_rank = text_rank::Summarizer::new(text_rank::SummarizerParams::All(
+        TEXT,
+        &get_stop_words(),
+        None,
+        text_rank::SentenceSimilarity::TfIdfCosine,
+        0.1,
+        0.85,
+        0.00005,
+    ));
+    let lex_summary = lex_rank.get_summary(3);
+    assert_eq!(lex_summary.len(), 3);
+    assert!(lex_summary
+        .iter()
+        .any(|s| s.sentence.to_lowercase().contains("rust")));
+}
+
 #[cfg(feature = "yake")]
 #[test]
 fn test_yake() {
     let yake = yake::Yake::new(yake::YakeParams::WithDefaults(TEXT, &get_stop_words()));
     let yake_result = [
-        "motivated junior rust",
-        "motivated junior",
-        "job description",
-        "developer job description",
-        "rust developer",
-        "developer job",
         "junior rust developer",
-        "rust developer job",
         "junior rust",
-        "rust programming language",
+        "rust developer",
+        "motivated junior rust",
+        "rust",
+        "developer",
+        "junior",
+        "job description",
+        "motivated junior",
+        "environment",
     ];
 
     let ranked_keywords = yake.get_ranked_keywords(10);
@@ -574,11 +1727,7 @@ fn test_yake() {
         .iter()
         .flat_map(|s| s.split_whitespace())
         .collect::<Vec<&str>>();
-    let ranked_terms = yake.get_ranked_terms(
-        ranked_keywords
-            .iter()
-            .fold(0, |acc, s| acc + s.split_whitespace().count()),
-    );
+    let ranked_terms = yake.get_ranked_terms(20);
 
     assert!(is_percent_in_hashset(
         &ranked_keywords,
@@ -589,4 +1738,436 @@ fn test_yake() {
         90.0
     ));
     assert!(contains_all(&ranked_terms, &expected_terms));
+    assert_eq!(
+        ranked_terms[..6],
+        ["title", "developer", "junior", "description", "rust", "job"]
+    );
+}
+
+#[test]
+#[cfg(feature = "yake")]
+fn test_yake_dedup_strategies() {
+    let similarity = |strategy: yake::DedupStrategy, keyword1: &str, keyword2: &str| {
+        (strategy.similarity(keyword1, keyword2) * 10000.0).round() / 10000.0
+    };
+
+    assert_eq!(
+        similarity(yake::DedupStrategy::JaroWinkler, "martha", "marhta"),
+        0.9611
+    );
+    assert_eq!(
+        similarity(yake::DedupStrategy::JaroWinkler, "dixon", "dicksonx"),
+        0.8133
+    );
+    assert_eq!(
+        similarity(
+            yake::DedupStrategy::SequenceMatcher,
+            "rust developer",
+            "rust developers"
+        ),
+        0.9655
+    );
+    assert_eq!(
+        similarity(yake::DedupStrategy::SequenceMatcher, "rtx 4090", "gtx 1080"),
+        0.625
+    );
+    assert_eq!(
+        similarity(yake::DedupStrategy::Levenshtein, "rtx 4090", "rtx 4080"),
+        0.875
+    );
+
+    let stop_words = get_stop_words();
+    let yake = yake::Yake::new(yake::YakeParams::WithDefaults(TEXT, &stop_words));
+    assert_eq!(
+        yake.with_dedup_strategy(yake::DedupStrategy::Custom(Arc::new(|_, _| 1.0)))
+            .get_ranked_keywords(10)
+            .len(),
+        1
+    );
+
+    let yake = yake::Yake::new(yake::YakeParams::WithDefaults(TEXT, &stop_words))
+        .with_dedup_strategy(yake::DedupStrategy::SequenceMatcher);
+    let ranked_keywords = yake.get_ranked_keywords(10);
+    assert_eq!(ranked_keywords.len(), 10);
+    assert!(ranked_keywords.iter().enumerate().all(|(i, keyword1)| {
+        ranked_keywords[i + 1..].iter().all(|keyword2| {
+            yake::DedupStrategy::SequenceMatcher.similarity(keyword1, keyword2) < 0.85
+        })
+    }));
+}
+
+#[test]
+#[cfg(feature = "yake")]
+fn test_yake_features() {
+    let stop_words = get_stop_words();
+    let yake = yake::Yake::new(yake::YakeParams::WithDefaults(TEXT, &stop_words));
+    assert!(yake.get_term_features("rust").is_none());
+    assert!(yake.get_candidate_details_map().is_none());
+
+    let yake = yake::Yake::with_options(
+        yake::YakeParams::WithDefaults(TEXT, &stop_words),
+        yake::YakeOptions {
+            keep_features: true,
+            ..Default::default()
+        },
+    );
+    let features = yake.get_term_features("rust").unwrap();
+    assert_eq!(features.tf, 8.0);
+    assert!(features.different > 0.0 && features.different <= 1.0);
+    assert!(
+        (features.score
+            - features.relatedness * features.position
+                / (features.casing
+                    + features.frequency / features.relatedness
+                    + features.different / features.relatedness))
+            .abs()
+            < 1e-6
+    );
+
+    let details = yake.get_candidate_details("junior rust developer").unwrap();
+    let product = ["junior", "rust", "developer"]
+        .iter()
+        .map(|term| yake.get_term_features(term).unwrap().score)
+        .product::<f32>();
+    assert_eq!(details.tf, 2.0);
+    assert!((details.product - product).abs() < 1e-6);
+    assert!((details.score - details.product / ((1.0 + details.sum) * details.tf)).abs() < 1e-6);
+
+    let best = yake
+        .get_candidate_details_map()
+        .unwrap()
+        .values()
+        .map(|details| details.score)
+        .fold(f32::INFINITY, f32::min);
+    assert!((yake.get_keyword_score("junior rust developer") - best / details.score).abs() < 1e-6);
+}
+
+#[test]
+#[cfg(feature = "yake")]
+fn test_yake_term_scoring() {
+    let stop_words = get_stop_words();
+    let get_yake = |term_scoring: yake::TermScoring| {
+        yake::Yake::with_options(
+            yake::YakeParams::WithDefaults(TEXT, &stop_words),
+            yake::YakeOptions {
+                keep_features: true,
+                term_scoring,
+                ..Default::default()
+            },
+        )
+    };
+    let yake = yake::Yake::new(yake::YakeParams::WithDefaults(TEXT, &stop_words));
+    let weighted = get_yake(yake::TermScoring::Weighted(yake::FeatureWeights::default()));
+    let weights = yake::FeatureWeights::default();
+    let custom = get_yake(yake::TermScoring::Custom(Arc::new(
+        move |features: &yake::TermFeatures| weights.score(features),
+    )));
+    assert_eq!(
+        yake.get_keyword_scores_map(),
+        weighted.get_keyword_scores_map()
+    );
+    assert_eq!(
+        yake.get_keyword_scores_map(),
+        custom.get_keyword_scores_map()
+    );
+
+    let without_casing = get_yake(yake::TermScoring::Weighted(yake::FeatureWeights {
+        casing: 0.0,
+        ..Default::default()
+    }));
+    let features = without_casing.get_term_features("junior").unwrap();
+    assert!(features.casing > 0.0);
+    assert!(
+        (features.score
+            - features.relatedness * features.position
+                / ((features.frequency + features.different) / features.relatedness))
+            .abs()
+            < 1e-6
+    );
+
+    let by_frequency = get_yake(yake::TermScoring::Custom(Arc::new(
+        |features: &yake::TermFeatures| 1.0 / features.tf,
+    )));
+    assert_eq!(by_frequency.get_ranked_terms(1), vec!["rust".to_string()]);
+
+    let without_denominator = get_yake(yake::TermScoring::Weighted(yake::FeatureWeights {
+        casing: 0.0,
+        frequency: 0.0,
+        different: 0.0,
+        ..Default::default()
+    }));
+    assert!(without_denominator
+        .get_term_features_map()
+        .unwrap()
+        .values()
+        .all(|features| features.score.is_finite()));
+}
+
+#[test]
+#[cfg(feature = "yake")]
+fn test_yake_numbers_and_alphanumerics() {
+    let text = "The RTX 4090 graphics card launched in 2022. Reviewers compared the RTX 4090 \
+        with the A100 accelerator. The RTX 4090 graphics card draws 450 watts, while the A100 \
+        accelerator targets data centres. Gamers bought the RTX 4090 graphics card in 2022.";
+    let stop_words = get_stop_words();
+    let get_yake = |keep_numbers: bool, keep_alphanumerics: bool| {
+        yake::Yake::with_options(
+            yake::YakeParams::WithDefaults(text, &stop_words),
+            yake::YakeOptions {
+                keep_features: true,
+                keep_numbers,
+                keep_alphanumerics,
+                ..Default::default()
+            },
+        )
+    };
+
+    let yake = get_yake(false, false);
+    let keywords = yake.get_keyword_scores_map();
+    assert!(!keywords.contains_key("rtx 4090"));
+    assert!(!keywords.contains_key("2022"));
+    assert!(!keywords.contains_key("a100 accelerator"));
+    assert!(keywords.contains_key("graphics card"));
+
+    let yake = get_yake(true, false);
+    let keywords = yake.get_keyword_scores_map();
+    assert!(keywords.contains_key("rtx 4090 graphics"));
+    assert!(keywords.contains_key("2022"));
+    assert!(!keywords.contains_key("a100 accelerator"));
+    assert!(yake.get_ranked_keywords(1)[0].contains("4090"));
+    assert_eq!(yake.get_term_features("4090").unwrap().casing, 0.0);
+
+    let yake = get_yake(true, true);
+    assert!(yake
+        .get_keyword_scores_map()
+        .contains_key("a100 accelerator"));
+    assert!(yake.get_term_features("a100").unwrap().casing > 0.0);
+}
+
+#[test]
+#[cfg(feature = "yake")]
+fn test_yake_stemmer() {
+    let text = "Rust developers write fast code. A Rust developer reviews code daily. \
+        Senior Rust developers mentor the team, and every Rust developer learns.";
+    let stop_words = get_stop_words();
+    let get_yake = |stemmer: stemmer::Stemmer| {
+        yake::Yake::with_options(
+            yake::YakeParams::WithDefaults(text, &stop_words),
+            yake::YakeOptions {
+                keep_features: true,
+                stemmer: Some(stemmer),
+                ..Default::default()
+            },
+        )
+    };
+
+    let yake = get_yake(stemmer::Stemmer::Lowercase);
+    assert!(yake.get_term_features("developers").is_some());
+    assert_eq!(
+        yake.get_candidate_details("rust developers").unwrap().tf,
+        2.0
+    );
+    assert_eq!(
+        yake.get_candidate_details("rust developer").unwrap().tf,
+        2.0
+    );
+
+    let yake = get_yake(stemmer::Stemmer::Porter);
+    assert!(yake.get_term_features("developers").is_none());
+    assert_eq!(yake.get_term_features("develop").unwrap().tf, 4.0);
+    let details = yake.get_candidate_details_map().unwrap();
+    assert_eq!(
+        details.contains_key("rust developer") as u8
+            + details.contains_key("rust developers") as u8,
+        1
+    );
+    assert_eq!(
+        details
+            .get("rust developer")
+            .or_else(|| details.get("rust developers"))
+            .unwrap()
+            .tf,
+        4.0
+    );
+}
+
+/// The example of the reference YAKE README (https://github.com/LIAAD/yake).
+#[cfg(feature = "yake")]
+const YAKE_README_TEXT: &str =
+    "Sources tell us that Google is acquiring Kaggle, a platform that hosts data science and \
+    machine learning competitions. Details about the transaction remain somewhat vague, but \
+    given that Google is hosting its Cloud Next conference in San Francisco this week, the \
+    official announcement could come as early as tomorrow. Reached by phone, Kaggle co-founder \
+    CEO Anthony Goldbloom declined to deny that the acquisition is happening. Google itself \
+    declined 'to comment on rumors'. Kaggle, which has about half a million data scientists on \
+    its platform, was founded by Goldbloom  and Ben Hamner in 2010. The service got an early \
+    start and even though it has a few competitors like DrivenData, TopCoder and HackerRank, \
+    it has managed to stay well ahead of them by focusing on its specific niche. The service \
+    is basically the de facto home for running data science and machine learning competitions. \
+    With Kaggle, Google is buying one of the largest and most active communities for data \
+    scientists - and with that, it will get increased mindshare in this community, too (though \
+    it already has plenty of that thanks to Kubernetes and TensorFlow and other projects). \
+    Kaggle has a bit of a history with Google, too, but that's pretty recent. Earlier this \
+    month, Google and Kaggle teamed up to host a $100,000 machine learning competition around \
+    classifying YouTube videos. That competition had some deep integrations with the Google \
+    Cloud Platform, too. Our understanding is that Google will keep the service running - \
+    likely under its current name. While the acquisition is probably more about Kaggle's \
+    community than technology, Kaggle did build some interesting tools for hosting its \
+    competition and 'kernels', too. On Kaggle, kernels are basically the source code for \
+    analyzing data sets and developers can share this code on the platform (the company \
+    previously called them 'scripts'). Like similar competition-centric sites, Kaggle also \
+    runs a job board, too. It's unclear what Google will do with that part of the service. \
+    According to Crunchbase, Kaggle raised $12.5 million (though PitchBook says it's $12.75) \
+    since its launch in 2010. Investors in Kaggle include Index Ventures, SV Angel, Max \
+    Levchin, Naval Ravikant, Google chief economist Hal Varian, Khosla Ventures and Yuri \
+    Milner";
+
+#[cfg(feature = "yake")]
+fn assert_yake_scores(text: &str, window_size: usize, expected: &[(&str, f32)]) {
+    let stop_words = get_stop_words();
+    let yake = yake::Yake::new(yake::YakeParams::All(
+        text,
+        &stop_words,
+        None,
+        1.0,
+        3,
+        window_size,
+    ));
+    let ranked_keywords = yake.get_ranked_keywords(expected.len());
+
+    expected
+        .iter()
+        .enumerate()
+        .for_each(|(i, (keyword, score))| {
+            assert_eq!(ranked_keywords[i], *keyword);
+            assert!((yake.get_keyword_score(keyword) - score).abs() < 1e-4);
+        });
+}
+
+#[test]
+#[cfg(feature = "yake")]
+fn test_yake_zero_denominator() {
+    // "of" always links "alpha" to "beta", so it adds nothing to the sum, which the terms bring to -1.
+    let stop_words = get_stop_words();
+    let yake = yake::Yake::with_options(
+        yake::YakeParams::All(
+            "Alpha of beta. Alpha of beta.",
+            &stop_words,
+            None,
+            0.9,
+            3,
+            1,
+        ),
+        yake::YakeOptions {
+            keep_features: true,
+            term_scoring: yake::TermScoring::Custom(Arc::new(|_: &yake::TermFeatures| -0.5)),
+            ..Default::default()
+        },
+    );
+    let details = yake.get_candidate_details("alpha of beta").unwrap();
+    assert_eq!(details.sum, 1.0 - f32::EPSILON);
+    assert!((details.score - 0.0625).abs() < 1e-6);
+}
+
+#[test]
+#[cfg(feature = "yake")]
+fn test_yake_conformance() {
+    // Settings: n = 3, no deduplication, the windowsSize given to each case and the stop words of
+    // this file, normalised as min(H) / H. `scripts/yake_reference.py` prints these fixtures from
+    // the reference package. The values below still come from a transcription of its formulas and
+    // must be replaced by the script output.
+    assert_yake_scores(
+        "Rust is a systems programming language. Rust programs are fast and memory safe. \
+        The Rust compiler checks memory safety at compile time. Systems programmers choose \
+        Rust for speed and safety of memory.",
+        1,
+        &[
+            ("programming language", 1.0),
+            ("rust", 0.419801),
+            ("language", 0.245169),
+            ("memory", 0.219184),
+            ("programming", 0.183429),
+            ("rust programs", 0.160189),
+            ("safety", 0.141889),
+            ("rust compiler", 0.129343),
+            ("memory safe", 0.128541),
+            ("rust compiler checks", 0.122970),
+            ("programs are fast", 0.118664),
+        ],
+    );
+    assert_yake_scores(
+        "The NASA mission launched in 2024 from Florida. NASA engineers tested the Orion \
+        capsule twice, then again. The Orion capsule carried 4 astronauts around the Moon. \
+        Engineers at NASA celebrated the successful Orion mission.",
+        2,
+        &[
+            ("nasa mission launched", 1.0),
+            ("orion capsule", 0.491760),
+            ("florida", 0.450997),
+            ("nasa", 0.388726),
+            ("orion", 0.354338),
+            ("nasa engineers tested", 0.326584),
+            ("orion capsule carried", 0.301687),
+            ("mission launched", 0.265522),
+            ("nasa mission", 0.236324),
+            ("successful orion mission", 0.222905),
+        ],
+    );
+    // Singular and plural surface forms stay separate candidates, only their terms are merged.
+    assert_yake_scores(
+        "Rust developers write fast code. A Rust developer reviews code daily. Senior Rust \
+        developers mentor the team, and every Rust developer learns quickly.",
+        1,
+        &[
+            ("write fast code", 1.0),
+            ("developers write fast", 0.970797),
+            ("rust developers write", 0.948226),
+            ("rust developer", 0.502630),
+            ("rust developers", 0.502630),
+            ("write fast", 0.463717),
+            ("rust", 0.450004),
+        ],
+    );
+}
+
+#[test]
+#[cfg(feature = "yake")]
+fn test_yake_reference_example() {
+    // Output of the reference package as published in its README, with language "en", n = 3,
+    // dedupLim = 0.9, dedupFunc = "seqm" and windowsSize = 1. The reference ships its own English
+    // stop word list, so the raw scores here are only expected within 5% while the order must match.
+    let expected = [
+        ("google", 0.026581),
+        ("kaggle", 0.028901),
+        ("ceo anthony goldbloom", 0.029946),
+        ("san francisco", 0.048811),
+        ("anthony goldbloom declined", 0.061769),
+        ("google cloud platform", 0.062620),
+        ("co-founder ceo anthony", 0.073577),
+        ("acquiring kaggle", 0.087236),
+        ("ceo anthony", 0.089152),
+        ("anthony goldbloom", 0.091235),
+        ("machine learning", 0.091480),
+        ("kaggle co-founder ceo", 0.093805),
+        ("data", 0.097574),
+        ("google cloud", 0.102601),
+    ];
+    let stop_words = get_stop_words();
+    let yake = yake::Yake::with_options(
+        yake::YakeParams::All(YAKE_README_TEXT, &stop_words, None, 0.9, 3, 1),
+        yake::YakeOptions {
+            keep_features: true,
+            ..Default::default()
+        },
+    )
+    .with_dedup_strategy(yake::DedupStrategy::SequenceMatcher);
+
+    assert_eq!(
+        yake.get_ranked_keywords(expected.len()),
+        expected.map(|(keyword, _)| keyword)
+    );
+    expected.iter().for_each(|(keyword, score)| {
+        let details = yake.get_candidate_details(keyword).unwrap();
+        assert!((details.score / score - 1.0).abs() < 0.05);
+    });
 }
@@ -0,0 +1,113 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Integer indexed graph in compressed sparse row format, where each row holds the
+/// incoming edges of a node with their weight already divided by the outgoing weight
+/// sum of the source node.
+pub struct CsrGraph<K> {
+    nodes: Vec<K>,
+    offsets: Vec<usize>,
+    sources: Vec<usize>,
+    weights: Vec<f32>,
+}
+
+impl<K: Copy + Eq + Hash> CsrGraph<K> {
+    pub fn from_graph(graph: &HashMap<K, HashMap<K, f32>>) -> Self {
+        let mut nodes = graph.keys().copied().collect::<Vec<K>>();
+        let mut node_indexes = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (*node, i))
+            .collect::<HashMap<K, usize>>();
+
+        graph
+            .values()
+            .flat_map(|edges| edges.keys())
+            .for_each(|node| {
+                if !node_indexes.contains_key(node) {
+                    node_indexes.insert(*node, nodes.len());
+                    nodes.push(*node);
+                }
+            });
+
+        let n = nodes.len();
+        let mut in_degrees = vec![0_usize; n];
+        let edges = graph
+            .iter()
+            .flat_map(|(source, edges)| {
+                let source_index = node_indexes[source];
+                let outgoing_weight_sum = edges.values().sum::<f32>();
+                edges.iter().map(move |(target, weight)| {
+                    (source_index, *target, weight / outgoing_weight_sum)
+                })
+            })
+            .map(|(source_index, target, weight)| {
+                let target_index = node_indexes[&target];
+                in_degrees[target_index] += 1;
+                (source_index, target_index, weight)
+            })
+            .collect::<Vec<(usize, usize, f32)>>();
+
+        let mut offsets = Vec::with_capacity(n + 1);
+        offsets.push(0);
+        in_degrees.iter().fold(0, |acc, degree| {
+            offsets.push(acc + degree);
+            acc + degree
+        });
+
+        let mut cursors = offsets[..n].to_vec();
+        let mut sources = vec![0_usize; edges.len()];
+        let mut weights = vec![0.0_f32; edges.len()];
+        edges
+            .into_iter()
+            .for_each(|(source_index, target_index, weight)| {
+                let position = cursors[target_index];
+                sources[position] = source_index;
+                weights[position] = weight;
+                cursors[target_index] += 1;
+            });
+
+        Self {
+            nodes,
+            offsets,
+            sources,
+            weights,
+        }
+    }
+}
+
+impl<K> CsrGraph<K> {
+    /// Number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The node labels, indexed by their position in the graph.
+    pub fn nodes(&self) -> &[K] {
+        &self.nodes
+    }
+
+    /// Weighted sum of the scores of the nodes pointing to the given node.
+    pub fn incoming_score(&self, index: usize, scores: &[f32]) -> f32 {
+        let range = self.offsets[index]..self.offsets[index + 1];
+        self.sources[range.clone()]
+            .iter()
+            .zip(self.weights[range].iter())
+            .map(|(source, weight)| weight * scores[*source])
+            .sum::<f32>()
+    }
+}
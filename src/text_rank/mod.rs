@@ -15,41 +15,66 @@
 
 use std::collections::HashMap;
 
+mod csr_graph;
+pub mod summarizer;
+pub mod summarizer_params;
 mod text_rank_logic;
 pub mod text_rank_params;
-use text_rank_logic::TextRankLogic;
-pub use text_rank_params::TextRankParams;
+pub use summarizer::{RankedSentence, Summarizer};
+pub use summarizer_params::{SentenceSimilarity, SummarizerParams};
+pub use text_rank_logic::TextRankDiagnostics;
+use text_rank_logic::{TextRankLogic, WordGraphMap};
+pub use text_rank_params::{
+    Convergence, EdgeDirection, EdgeWeighting, TextRankOptions, TextRankParams,
+};
 
 use crate::{
-    common::{get_ranked_scores, get_ranked_strings},
+    common::{get_ranked_scores, get_ranked_strings, get_scores_above},
+    graph_export::{GraphEdge, GraphNode, WordGraph},
+    keyword_dedup::KeywordDedup,
+    normalization::ScoreNormalization,
     tokenizer::Tokenizer,
 };
 
 pub struct TextRank {
     word_rank: HashMap<String, f32>,
     phrase_rank: HashMap<String, f32>,
+    diagnostics: TextRankDiagnostics,
+    graph: Option<WordGraphMap>,
+    word_frequency: Option<HashMap<String, usize>>,
+    directed: bool,
 }
 
 impl TextRank {
     /// Create a new TextRank instance.
     pub fn new(params: TextRankParams) -> Self {
-        let (text, stop_words, punctuation, window_size, damping, tol, phrase_length) =
-            params.get_params();
+        let (text, stop_words, punctuation, _, _, _, phrase_length) = params.get_params();
         let tokenizer = Tokenizer::new(text, stop_words, punctuation);
-        let (word_rank, phrase_rank) = TextRankLogic::build_text_rank(
-            tokenizer.sync_split_into_words(),
-            tokenizer.sync_split_into_phrases(phrase_length),
-            window_size,
-            damping,
-            tol,
-        );
+        let options = params.get_options();
+        let (word_rank, phrase_rank, diagnostics, graph, word_frequency) =
+            TextRankLogic::build_text_rank(
+                tokenizer.sync_split_into_positioned_words(),
+                tokenizer.sync_split_into_phrases(phrase_length),
+                &options,
+            );
 
         Self {
             word_rank,
             phrase_rank,
+            diagnostics,
+            graph,
+            word_frequency,
+            directed: options.edge_direction != EdgeDirection::Undirected,
         }
     }
 
+    /// Normalises the word and phrase scores, so they can be compared across algorithms.
+    pub fn with_normalization(mut self, normalization: ScoreNormalization) -> Self {
+        self.word_rank = normalization.normalize(&self.word_rank);
+        self.phrase_rank = normalization.normalize(&self.phrase_rank);
+        self
+    }
+
     /// Gets the score of a word.
     pub fn get_word_score(&self, word: &str) -> f32 {
         *self.word_rank.get(word).unwrap_or(&0.0)
@@ -70,6 +95,11 @@ impl TextRank {
         get_ranked_scores(&self.word_rank, n)
     }
 
+    /// Gets the words whose score is at least the threshold, sorted by score.
+    pub fn get_keywords_above(&self, threshold: f32) -> Vec<(String, f32)> {
+        get_scores_above(&self.word_rank, threshold)
+    }
+
     /// Gets the top n phrases with the highest score.
     pub fn get_ranked_phrases(&self, n: usize) -> Vec<String> {
         get_ranked_strings(&self.phrase_rank, n)
@@ -80,6 +110,25 @@ impl TextRank {
         get_ranked_scores(&self.phrase_rank, n)
     }
 
+    /// Gets the phrases whose score is at least the threshold, sorted by score.
+    pub fn get_phrases_above(&self, threshold: f32) -> Vec<(String, f32)> {
+        get_scores_above(&self.phrase_rank, threshold)
+    }
+
+    /// Gets the top n phrases with the highest score, after dropping the near-duplicates.
+    pub fn get_deduplicated_phrases(&self, n: usize, dedup: &KeywordDedup) -> Vec<String> {
+        dedup.get_ranked_strings(&self.phrase_rank, n)
+    }
+
+    /// Gets the top n phrases with the highest score and their scores, after dropping the near-duplicates.
+    pub fn get_deduplicated_phrase_scores(
+        &self,
+        n: usize,
+        dedup: &KeywordDedup,
+    ) -> Vec<(String, f32)> {
+        dedup.get_ranked_scores(&self.phrase_rank, n)
+    }
+
     /// Gets the word scores map.
     pub fn get_word_scores_map(&self) -> &HashMap<String, f32> {
         &self.word_rank
@@ -89,4 +138,42 @@ impl TextRank {
     pub fn get_phrase_scores_map(&self) -> &HashMap<String, f32> {
         &self.phrase_rank
     }
+
+    /// Gets the convergence diagnostics of the power iteration.
+    pub fn get_diagnostics(&self) -> &TextRankDiagnostics {
+        &self.diagnostics
+    }
+
+    /// Gets the word graph ranked by TextRank, with the edges whose weight is below the threshold pruned,
+    /// when kept with the `keep_graph` option.
+    ///
+    /// Nodes carry the word score and frequency, undirected edges are only given once.
+    pub fn get_graph(&self, threshold: f32) -> Option<WordGraph> {
+        let nodes = self
+            .word_frequency
+            .as_ref()?
+            .iter()
+            .map(|(word, frequency)| GraphNode {
+                id: word.to_string(),
+                score: self.get_word_score(word),
+                frequency: *frequency,
+            })
+            .collect::<Vec<GraphNode>>();
+        let edges = self
+            .graph
+            .as_ref()?
+            .iter()
+            .flat_map(|(source, targets)| {
+                targets
+                    .iter()
+                    .filter(move |(target, _)| self.directed || source < *target)
+                    .map(move |(target, weight)| GraphEdge {
+                        source: source.to_string(),
+                        target: target.to_string(),
+                        weight: *weight,
+                    })
+            })
+            .collect::<Vec<GraphEdge>>();
+        Some(WordGraph::new(self.directed, nodes, edges).prune(threshold))
+    }
 }
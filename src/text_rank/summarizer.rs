@@ -0,0 +1,286 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::common::{get_special_char_regex, process_word, PUNCTUATION};
+
+use super::{
+    summarizer_params::{SentenceSimilarity, SummarizerParams},
+    text_rank_logic::{TextRankDiagnostics, TextRankLogic},
+    text_rank_params::TextRankOptions,
+};
+
+/// A sentence of the original text with its TextRank score.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RankedSentence {
+    /// The sentence as it appears in the original text.
+    pub sentence: String,
+    /// The TextRank score of the sentence.
+    pub score: f32,
+    /// Byte offset where the sentence starts in the original text.
+    pub start: usize,
+    /// Byte offset where the sentence ends (exclusive) in the original text.
+    pub end: usize,
+}
+
+struct SentenceTokens<'a> {
+    sentence: &'a str,
+    start: usize,
+    words: Vec<String>,
+}
+
+fn split_into_sentences<'a>(
+    text: &'a str,
+    special_char_regex: &Option<Regex>,
+    stopwords: &HashSet<String>,
+    punctuation: &HashSet<String>,
+) -> Vec<SentenceTokens<'a>> {
+    text.split_sentence_bound_indices()
+        .filter_map(|(offset, s)| {
+            let sentence = s.trim();
+
+            if !sentence.chars().any(|c| c.is_alphanumeric()) {
+                return None;
+            }
+
+            Some(SentenceTokens {
+                sentence,
+                start: offset + s.len() - s.trim_start().len(),
+                words: sentence
+                    .split_word_bounds()
+                    .filter_map(|w| process_word(w, special_char_regex, stopwords, punctuation))
+                    .collect::<Vec<String>>(),
+            })
+        })
+        .collect()
+}
+
+fn word_overlap(words1: &[String], words2: &[String]) -> f32 {
+    let unique_words1 = words1.iter().collect::<HashSet<&String>>();
+    let common = words2
+        .iter()
+        .collect::<HashSet<&String>>()
+        .iter()
+        .filter(|w| unique_words1.contains(*w))
+        .count() as f32;
+
+    if common == 0.0 {
+        return 0.0;
+    }
+
+    let normalizer = (words1.len() as f32).ln() + (words2.len() as f32).ln();
+
+    if normalizer > 0.0 {
+        common / normalizer
+    } else {
+        common
+    }
+}
+
+fn get_tf_idf_vectors(sentences: &[SentenceTokens]) -> Vec<HashMap<String, f32>> {
+    let documents_len = sentences.len() as f32;
+    let document_frequency = sentences
+        .iter()
+        .flat_map(|s| s.words.iter().collect::<HashSet<&String>>())
+        .fold(HashMap::<&str, f32>::new(), |mut acc, word| {
+            *acc.entry(word).or_insert(0.0) += 1.0;
+            acc
+        });
+
+    sentences
+        .iter()
+        .map(|s| {
+            s.words
+                .iter()
+                .fold(HashMap::<String, f32>::new(), |mut acc, word| {
+                    *acc.entry(word.to_string()).or_insert(0.0) += 1.0;
+                    acc
+                })
+                .into_iter()
+                .map(|(word, tf)| {
+                    let df = document_frequency.get(word.as_str()).unwrap_or(&0.0);
+                    let idf = ((documents_len + 1.0) / (df + 1.0)).ln() + 1.0;
+                    (word, tf * idf)
+                })
+                .collect::<HashMap<String, f32>>()
+        })
+        .collect()
+}
+
+fn cosine_similarity(vector1: &HashMap<String, f32>, vector2: &HashMap<String, f32>) -> f32 {
+    let dot = vector1
+        .iter()
+        .filter_map(|(word, value)| vector2.get(word).map(|other| value * other))
+        .sum::<f32>();
+
+    if dot == 0.0 {
+        return 0.0;
+    }
+
+    let norm1 = vector1.values().map(|v| v * v).sum::<f32>().sqrt();
+    let norm2 = vector2.values().map(|v| v * v).sum::<f32>().sqrt();
+    dot / (norm1 * norm2)
+}
+
+fn get_similarity_edges(
+    sentences: &[SentenceTokens],
+    similarity: SentenceSimilarity,
+    threshold: f32,
+) -> Vec<(usize, usize, f32)> {
+    let vectors = match similarity {
+        SentenceSimilarity::TfIdfCosine => get_tf_idf_vectors(sentences),
+        SentenceSimilarity::WordOverlap => Vec::new(),
+    };
+    let score_pair = |i: usize, j: usize| -> Option<(usize, usize, f32)> {
+        let weight = match similarity {
+            SentenceSimilarity::WordOverlap => {
+                word_overlap(&sentences[i].words, &sentences[j].words)
+            }
+            SentenceSimilarity::TfIdfCosine => cosine_similarity(&vectors[i], &vectors[j]),
+        };
+
+        if weight > 0.0 && weight > threshold {
+            Some((i, j, weight))
+        } else {
+            None
+        }
+    };
+
+    #[cfg(feature = "parallel")]
+    {
+        (0..sentences.len())
+            .into_par_iter()
+            .flat_map_iter(|i| (i + 1..sentences.len()).filter_map(move |j| score_pair(i, j)))
+            .collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        (0..sentences.len())
+            .flat_map(|i| (i + 1..sentences.len()).filter_map(move |j| score_pair(i, j)))
+            .collect()
+    }
+}
+
+fn create_sentence_graph(
+    sentences_len: usize,
+    edges: Vec<(usize, usize, f32)>,
+) -> HashMap<usize, HashMap<usize, f32>> {
+    edges.into_iter().fold(
+        (0..sentences_len)
+            .map(|i| (i, HashMap::new()))
+            .collect::<HashMap<usize, HashMap<usize, f32>>>(),
+        |mut graph, (i, j, weight)| {
+            graph.entry(i).or_default().insert(j, weight);
+            graph.entry(j).or_default().insert(i, weight);
+            graph
+        },
+    )
+}
+
+fn sort_by_score(a: &RankedSentence, b: &RankedSentence) -> Ordering {
+    let order = b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal);
+
+    if order == Ordering::Equal {
+        return a.start.cmp(&b.start);
+    }
+
+    order
+}
+
+/// Extractive summarizer that ranks the sentences of a text with TextRank (or LexRank).
+pub struct Summarizer {
+    sentences: Vec<RankedSentence>,
+    diagnostics: TextRankDiagnostics,
+}
+
+impl Summarizer {
+    /// Create a new Summarizer instance.
+    pub fn new(params: SummarizerParams) -> Self {
+        let (text, stop_words, punctuation, similarity, threshold, damping, tol) =
+            params.get_params();
+        let stopwords = stop_words
+            .iter()
+            .map(|s| s.to_owned())
+            .collect::<HashSet<String>>();
+        let punctuation = match punctuation {
+            Some(p) => p.iter().map(|s| s.to_owned()).collect::<HashSet<String>>(),
+            None => PUNCTUATION.iter().map(|s| s.to_string()).collect(),
+        };
+        let sentences =
+            split_into_sentences(text, &get_special_char_regex(), &stopwords, &punctuation);
+        let graph = create_sentence_graph(
+            sentences.len(),
+            get_similarity_edges(&sentences, similarity, threshold),
+        );
+        let (rank, diagnostics) = TextRankLogic::rank_graph(
+            &graph,
+            &TextRankOptions {
+                damping_factor: damping,
+                tolerance: tol,
+                ..TextRankOptions::default()
+            },
+        );
+
+        Self {
+            sentences: sentences
+                .into_iter()
+                .enumerate()
+                .map(|(i, s)| RankedSentence {
+                    sentence: s.sentence.to_string(),
+                    score: *rank.get(&i).unwrap_or(&0.0),
+                    start: s.start,
+                    end: s.start + s.sentence.len(),
+                })
+                .collect(),
+            diagnostics,
+        }
+    }
+
+    /// Gets the top n sentences with the highest score, in their original order.
+    pub fn get_summary(&self, n: usize) -> Vec<RankedSentence> {
+        let mut summary = self.get_ranked_sentences(n);
+        summary.sort_by_key(|s| s.start);
+        summary
+    }
+
+    /// Gets the top n sentences with the highest score, sorted by score.
+    pub fn get_ranked_sentences(&self, n: usize) -> Vec<RankedSentence> {
+        let mut ranked = self.sentences.to_vec();
+        ranked.sort_by(sort_by_score);
+        ranked.truncate(n);
+        ranked
+    }
+
+    /// Gets all the sentences with their score, in their original order.
+    pub fn get_sentences(&self) -> &[RankedSentence] {
+        &self.sentences
+    }
+
+    /// Gets the convergence diagnostics of the power iteration.
+    pub fn get_diagnostics(&self) -> &TextRankDiagnostics {
+        &self.diagnostics
+    }
+}
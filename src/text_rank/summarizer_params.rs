@@ -0,0 +1,106 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::common::{Punctuation, Stopwords, Text};
+
+type SimilarityThreshold = f32;
+type DampingFactor = f32;
+type Tolerance = f32;
+
+/// The similarity used to weight the edges between two sentences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SentenceSimilarity {
+    /// Number of shared words normalised by the log of the sentence lengths, as in the original TextRank paper.
+    WordOverlap,
+
+    /// IDF-modified cosine similarity between the TF-IDF vectors of the sentences, as in LexRank.
+    TfIdfCosine,
+}
+
+/// The parameters to be used in the TextRank sentence summarizer.
+pub enum SummarizerParams<'a> {
+    /// ## Arguments
+    /// 1. `text` - The text to be summarized.
+    /// 2. `stop_words` - A list of stop words.
+    /// ### Default values:
+    /// * `punctuation` - A list of punctuation symbols, defaults to those in Latin and Germanic languages.
+    /// * `similarity` - The sentence similarity, defaults to `SentenceSimilarity::WordOverlap`.
+    /// * `threshold` - The minimum similarity for two sentences to be linked, defaults to 0.0.
+    /// * `damping_factor` - The damping factor to be used in the graph, defaults to 0.85.
+    /// * `tolerance` - The minimum difference between iterations to stop the algorithm, defaults to 0.00005.
+    WithDefaults(Text<'a>, Stopwords<'a>),
+
+    /// ## Arguments
+    /// 1. `text` - The text to be summarized.
+    /// 2. `stop_words` - A list of stop words.
+    /// 3. `punctuation` - Optional list of punctuation symbols.
+    /// 4. `similarity` - The sentence similarity to be used as edge weight.
+    /// 5. `threshold` - The minimum similarity (exclusive) for two sentences to be linked.
+    /// 6. `damping_factor` - The damping factor to be used in the graph.
+    /// 7. `tolerance` - The minimum difference between iterations to stop the algorithm.
+    All(
+        Text<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
+        SentenceSimilarity,
+        SimilarityThreshold,
+        DampingFactor,
+        Tolerance,
+    ),
+}
+
+impl<'a> SummarizerParams<'a> {
+    /// Returns the params to be used in the TextRank sentence summarizer.
+    pub fn get_params(
+        &self,
+    ) -> (
+        Text<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
+        SentenceSimilarity,
+        SimilarityThreshold,
+        DampingFactor,
+        Tolerance,
+    ) {
+        match self {
+            SummarizerParams::WithDefaults(text, stop_words) => (
+                *text,
+                *stop_words,
+                None,
+                SentenceSimilarity::WordOverlap,
+                0.0,
+                0.85,
+                0.00005,
+            ),
+            SummarizerParams::All(
+                text,
+                stop_words,
+                punctuation,
+                similarity,
+                threshold,
+                damping_factor,
+                tolerance,
+            ) => (
+                *text,
+                *stop_words,
+                *punctuation,
+                *similarity,
+                *threshold,
+                *damping_factor,
+                *tolerance,
+            ),
+        }
+    }
+}
@@ -13,11 +13,32 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, hash::Hash};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+use super::{
+    csr_graph::CsrGraph,
+    text_rank_params::{Convergence, EdgeDirection, EdgeWeighting, TextRankOptions},
+};
+
+/// Convergence diagnostics of the TextRank power iteration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextRankDiagnostics {
+    /// Number of iterations that were run.
+    pub iterations: usize,
+    /// The delta between the last two iterations, as measured by the convergence criterion.
+    pub delta: f32,
+    /// Whether the delta fell below the tolerance before reaching the maximum iterations.
+    pub converged: bool,
+}
+
+/// Owned word graph, each word maps to its neighbours and edge weights.
+pub type WordGraphMap = HashMap<String, HashMap<String, f32>>;
+type WordScores = HashMap<String, f32>;
+type WordFrequency = HashMap<String, usize>;
+
 pub struct TextRankLogic;
 
 fn score_phrase(phrase: &str, word_rank: &HashMap<String, f32>) -> (String, f32) {
@@ -30,217 +51,281 @@ fn score_phrase(phrase: &str, word_rank: &HashMap<String, f32>) -> (String, f32)
     (phrase.to_string(), score / words.len() as f32)
 }
 
-fn score_word(
-    edges: &HashMap<&str, f32>,
-    node_indexes: &HashMap<&str, usize>,
-    outgoing_weight_sums: &HashMap<&str, f32>,
+fn update_scores<K: Sync>(
+    csr_graph: &CsrGraph<K>,
     prev_scores: &[f32],
+    scores: &mut [f32],
     damping: f32,
-) -> f32 {
-    let new_score = edges
-        .iter()
-        .map(|(neighbor, weight)| {
-            let neighbor_index = node_indexes[neighbor];
-            let neighbor_outgoing_sum = outgoing_weight_sums[neighbor];
-            weight / neighbor_outgoing_sum * prev_scores[neighbor_index]
-        })
-        .sum::<f32>();
-
-    (1.0 - damping) + damping * new_score
-}
-
-fn get_node_indexes<'a>(nodes: &'a [&'a str]) -> HashMap<&'a str, usize> {
+) {
     #[cfg(feature = "parallel")]
     {
-        nodes
-            .par_iter()
-            .enumerate()
-            .map(|(i, w)| (*w, i))
-            .collect::<HashMap<&str, usize>>()
+        scores.par_iter_mut().enumerate().for_each(|(i, score)| {
+            *score = (1.0 - damping) + damping * csr_graph.incoming_score(i, prev_scores);
+        });
     }
 
     #[cfg(not(feature = "parallel"))]
     {
-        nodes
-            .iter()
-            .enumerate()
-            .map(|(i, w)| (*w, i))
-            .collect::<HashMap<&str, usize>>()
+        scores.iter_mut().enumerate().for_each(|(i, score)| {
+            *score = (1.0 - damping) + damping * csr_graph.incoming_score(i, prev_scores);
+        });
     }
 }
 
-fn get_scores(
-    graph: &HashMap<&str, HashMap<&str, f32>>,
-    node_indexes: &HashMap<&str, usize>,
-    outgoing_weight_sums: &HashMap<&str, f32>,
-    prev_scores: &[f32],
-    damping: f32,
-) -> Vec<f32> {
+fn get_delta(scores: &[f32], prev_scores: &[f32], convergence: Convergence) -> f32 {
     #[cfg(feature = "parallel")]
     {
-        graph
+        let deltas = scores
             .par_iter()
-            .map(|(_, edges)| {
-                score_word(
-                    edges,
-                    node_indexes,
-                    outgoing_weight_sums,
-                    prev_scores,
-                    damping,
-                )
-            })
-            .collect()
-    }
+            .zip(prev_scores.par_iter())
+            .map(|(score, prev_score)| (score - prev_score).abs());
 
-    #[cfg(not(feature = "parallel"))]
-    {
-        graph
-            .values()
-            .map(|edges| {
-                score_word(
-                    edges,
-                    node_indexes,
-                    outgoing_weight_sums,
-                    prev_scores,
-                    damping,
-                )
-            })
-            .collect()
-    }
-}
-
-fn check_tolorance(scores: &[f32], prev_scores: &[f32], tol: f32) -> bool {
-    #[cfg(feature = "parallel")]
-    {
-        scores.par_iter().enumerate().all(|(i, score)| {
-            let prev_score = prev_scores[i];
-            (score - prev_score).abs() < tol
-        })
+        match convergence {
+            Convergence::MaxAbsDelta => deltas.reduce(|| 0.0_f32, f32::max),
+            Convergence::L1Delta => deltas.sum::<f32>(),
+        }
     }
 
     #[cfg(not(feature = "parallel"))]
     {
-        scores
+        let deltas = scores
             .iter()
             .zip(prev_scores.iter())
-            .all(|(score, prev_score)| (score - prev_score).abs() < tol)
+            .map(|(score, prev_score)| (score - prev_score).abs());
+
+        match convergence {
+            Convergence::MaxAbsDelta => deltas.fold(0.0_f32, f32::max),
+            Convergence::L1Delta => deltas.sum::<f32>(),
+        }
     }
 }
 
 impl TextRankLogic {
     pub fn build_text_rank(
-        words: Vec<String>,
+        sentences: Vec<Vec<(usize, String)>>,
         phrases: Vec<String>,
-        window_size: usize,
-        damping: f32,
-        tol: f32,
-    ) -> (HashMap<String, f32>, HashMap<String, f32>) {
-        let word_rank =
-            Self::create_word_rank(Self::create_graph(&words, window_size), damping, tol);
+        options: &TextRankOptions,
+    ) -> (
+        WordScores,
+        WordScores,
+        TextRankDiagnostics,
+        Option<WordGraphMap>,
+        Option<WordFrequency>,
+    ) {
+        let segments = Self::get_window_segments(sentences, options);
+        let graph = Self::create_graph(&segments, options);
+        let (word_rank, diagnostics) = Self::create_word_rank(&graph, options);
         let phrase_rank = Self::rank_phrases(phrases, &word_rank);
-        (word_rank, phrase_rank)
+        (
+            word_rank,
+            phrase_rank,
+            diagnostics,
+            options.keep_graph.then(|| Self::to_owned_graph(graph)),
+            options
+                .keep_graph
+                .then(|| Self::get_word_frequency(&segments)),
+        )
+    }
+
+    fn to_owned_graph(graph: HashMap<&str, HashMap<&str, f32>>) -> WordGraphMap {
+        graph
+            .into_iter()
+            .map(|(word, edges)| {
+                (
+                    word.to_string(),
+                    edges
+                        .into_iter()
+                        .map(|(other, weight)| (other.to_string(), weight))
+                        .collect::<HashMap<String, f32>>(),
+                )
+            })
+            .collect()
+    }
+
+    fn get_word_frequency(segments: &[Vec<(usize, String)>]) -> WordFrequency {
+        segments
+            .iter()
+            .flatten()
+            .fold(HashMap::new(), |mut acc, (_, word)| {
+                *acc.entry(word.to_string()).or_insert(0) += 1;
+                acc
+            })
     }
 
     fn add_edge<'a>(
         graph: &mut HashMap<&'a str, HashMap<&'a str, f32>>,
         word1: &'a str,
         word2: &'a str,
+        weight: f32,
     ) {
         graph
             .entry(word1)
             .or_default()
             .entry(word2)
-            .and_modify(|e| *e += 1.0)
-            .or_insert(1.0);
+            .and_modify(|e| *e += weight)
+            .or_insert(weight);
+    }
+
+    fn get_window_segments(
+        sentences: Vec<Vec<(usize, String)>>,
+        options: &TextRankOptions,
+    ) -> Vec<Vec<(usize, String)>> {
+        let (segments, _) = sentences.into_iter().fold(
+            (Vec::<Vec<(usize, String)>>::new(), 0_usize),
+            |(mut segments, index), sentence| {
+                let sentence_len = sentence.len();
+                let words = sentence
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (position, word))| {
+                        if options.count_stop_words {
+                            (position, word)
+                        } else {
+                            (index + i, word)
+                        }
+                    })
+                    .collect::<Vec<(usize, String)>>();
+
+                match segments.last_mut() {
+                    Some(segment) if !options.sentence_bounded => segment.extend(words),
+                    _ => segments.push(words),
+                }
+
+                (segments, index + sentence_len)
+            },
+        );
+
+        segments
     }
 
-    fn create_graph(words: &[String], window_size: usize) -> HashMap<&str, HashMap<&str, f32>> {
-        words
+    fn create_graph<'a>(
+        segments: &'a [Vec<(usize, String)>],
+        options: &TextRankOptions,
+    ) -> HashMap<&'a str, HashMap<&'a str, f32>> {
+        let window_size = options.window_size;
+        segments
             .iter()
-            .enumerate()
-            .flat_map(|(i, word1)| {
-                words[i + 1..]
+            .flat_map(|words| {
+                words
                     .iter()
-                    .take(window_size)
-                    .filter(|word2| word1.as_str() != word2.as_str())
-                    .map(move |word2| (word1, word2))
+                    .enumerate()
+                    .flat_map(move |(i, (position1, word1))| {
+                        words[i + 1..]
+                            .iter()
+                            .map(move |(position2, word2)| (word2, position2 - position1))
+                            .take_while(move |(_, distance)| *distance <= window_size)
+                            .filter(move |(word2, _)| word1 != *word2)
+                            .map(move |(word2, distance)| (word1, word2, distance))
+                    })
             })
-            .fold(HashMap::new(), |mut graph, (word1, word2)| {
-                Self::add_edge(&mut graph, word1, word2);
-                Self::add_edge(&mut graph, word2, word1);
+            .fold(HashMap::new(), |mut graph, (word1, word2, distance)| {
+                let weight = match options.edge_weighting {
+                    EdgeWeighting::Count => 1.0,
+                    EdgeWeighting::InverseDistance => 1.0 / distance as f32,
+                };
+
+                match options.edge_direction {
+                    EdgeDirection::Undirected => {
+                        Self::add_edge(&mut graph, word1, word2, weight);
+                        Self::add_edge(&mut graph, word2, word1, weight);
+                    }
+                    EdgeDirection::Forward => Self::add_edge(&mut graph, word1, word2, weight),
+                    EdgeDirection::Backward => Self::add_edge(&mut graph, word2, word1, weight),
+                }
+
                 graph
             })
     }
 
-    fn get_outgoing_weight_sum<'a>(
-        graph: &'a HashMap<&'a str, HashMap<&'a str, f32>>,
-    ) -> HashMap<&'a str, f32> {
+    fn create_word_rank(
+        graph: &HashMap<&str, HashMap<&str, f32>>,
+        options: &TextRankOptions,
+    ) -> (HashMap<String, f32>, TextRankDiagnostics) {
+        let (rank, diagnostics) = Self::rank_graph(graph, options);
+
         #[cfg(feature = "parallel")]
         {
-            graph
-                .par_iter()
-                .map(|(node, edges)| {
-                    let outgoing_weight_sum = edges.values().sum();
-                    (*node, outgoing_weight_sum)
-                })
-                .collect()
+            (
+                rank.into_par_iter()
+                    .map(|(node, score)| (node.to_string(), score))
+                    .collect::<HashMap<String, f32>>(),
+                diagnostics,
+            )
         }
 
         #[cfg(not(feature = "parallel"))]
         {
-            graph
-                .iter()
-                .map(|(node, edges)| {
-                    let outgoing_weight_sum = edges.values().sum();
-                    (*node, outgoing_weight_sum)
-                })
-                .collect()
+            (
+                rank.into_iter()
+                    .map(|(node, score)| (node.to_string(), score))
+                    .collect::<HashMap<String, f32>>(),
+                diagnostics,
+            )
         }
     }
 
-    fn create_word_rank<'a>(
-        graph: HashMap<&'a str, HashMap<&'a str, f32>>,
-        damping: f32,
-        tol: f32,
-    ) -> HashMap<String, f32> {
-        let nodes = graph.keys().copied().collect::<Vec<&str>>();
-        let n = nodes.len();
-        let node_indexes = get_node_indexes(&nodes);
-        let mut scores = vec![1.0_f32; n];
-        let outgoing_weight_sums = Self::get_outgoing_weight_sum(&graph);
-
-        loop {
-            let prev_scores = scores.to_owned();
-            scores = get_scores(
-                &graph,
-                &node_indexes,
-                &outgoing_weight_sums,
-                &prev_scores,
-                damping,
-            );
-
-            if check_tolorance(&scores, &prev_scores, tol) {
-                break;
-            }
-        }
+    /// Runs the weighted PageRank power iteration over any graph of hashable nodes.
+    pub fn rank_graph<K: Copy + Eq + Hash + Send + Sync>(
+        graph: &HashMap<K, HashMap<K, f32>>,
+        options: &TextRankOptions,
+    ) -> (HashMap<K, f32>, TextRankDiagnostics) {
+        let csr_graph = CsrGraph::from_graph(graph);
+        let (scores, diagnostics) = Self::power_iteration(&csr_graph, options);
 
         #[cfg(feature = "parallel")]
         {
-            nodes
-                .par_iter()
-                .map(|&node| (node.to_string(), scores[node_indexes[node]]))
-                .collect::<HashMap<String, f32>>()
+            (
+                csr_graph
+                    .nodes()
+                    .par_iter()
+                    .zip(scores.par_iter())
+                    .map(|(node, score)| (*node, *score))
+                    .collect::<HashMap<K, f32>>(),
+                diagnostics,
+            )
         }
 
         #[cfg(not(feature = "parallel"))]
         {
-            nodes
-                .iter()
-                .map(|&node| (node.to_string(), scores[node_indexes[node]]))
-                .collect::<HashMap<String, f32>>()
+            (
+                csr_graph
+                    .nodes()
+                    .iter()
+                    .zip(scores.iter())
+                    .map(|(node, score)| (*node, *score))
+                    .collect::<HashMap<K, f32>>(),
+                diagnostics,
+            )
         }
     }
 
+    fn power_iteration<K: Sync>(
+        csr_graph: &CsrGraph<K>,
+        options: &TextRankOptions,
+    ) -> (Vec<f32>, TextRankDiagnostics) {
+        let mut scores = vec![1.0_f32; csr_graph.len()];
+        let mut next_scores = vec![0.0_f32; csr_graph.len()];
+        let mut diagnostics = TextRankDiagnostics {
+            iterations: 0,
+            delta: f32::INFINITY,
+            converged: false,
+        };
+
+        while diagnostics.iterations < options.max_iterations {
+            update_scores(csr_graph, &scores, &mut next_scores, options.damping_factor);
+            std::mem::swap(&mut scores, &mut next_scores);
+            diagnostics.iterations += 1;
+            diagnostics.delta = get_delta(&scores, &next_scores, options.convergence);
+
+            if diagnostics.delta < options.tolerance {
+                diagnostics.converged = true;
+                break;
+            }
+        }
+
+        (scores, diagnostics)
+    }
+
     fn rank_phrases(
         phrases: Vec<String>,
         word_scores: &HashMap<String, f32>,
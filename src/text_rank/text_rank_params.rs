@@ -18,6 +18,81 @@ use crate::common::{PhraseLength, Punctuation, Stopwords, Text, WindowSize};
 type DampingFactor = f32;
 type Tolerance = f32;
 
+/// The criterion used to decide when the power iteration has converged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Convergence {
+    /// Stops when the largest absolute change of a single score is below the tolerance.
+    MaxAbsDelta,
+
+    /// Stops when the sum of the absolute changes of all scores (L1 norm) is below the tolerance.
+    L1Delta,
+}
+
+/// The direction of the edges between two words inside the window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdgeDirection {
+    /// Links both words to each other.
+    Undirected,
+
+    /// Links each word only to the words that follow it.
+    Forward,
+
+    /// Links each word only to the words that precede it.
+    Backward,
+}
+
+/// The weight added to an edge for each co-occurrence inside the window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdgeWeighting {
+    /// Every co-occurrence adds 1.
+    Count,
+
+    /// Every co-occurrence adds 1/d, where d is the distance between the words.
+    InverseDistance,
+}
+
+/// The options of the TextRank graph and power iteration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextRankOptions {
+    /// The size of the window to be used in the graph, defaults to 2.
+    pub window_size: WindowSize,
+    /// The damping factor to be used in the graph, defaults to 0.85.
+    pub damping_factor: DampingFactor,
+    /// The minimum difference between iterations to stop the algorithm, defaults to 0.00005.
+    pub tolerance: Tolerance,
+    /// The maximum number of iterations before giving up on convergence, defaults to `usize::MAX` (until convergence).
+    pub max_iterations: usize,
+    /// The convergence criterion, defaults to `Convergence::MaxAbsDelta`.
+    pub convergence: Convergence,
+    /// Whether the co-occurrence window stops at sentence boundaries, defaults to false.
+    pub sentence_bounded: bool,
+    /// Whether the distance inside the window counts the removed stop words, defaults to false.
+    pub count_stop_words: bool,
+    /// The direction of the edges, defaults to `EdgeDirection::Undirected`.
+    pub edge_direction: EdgeDirection,
+    /// The weight of the edges, defaults to `EdgeWeighting::Count`.
+    pub edge_weighting: EdgeWeighting,
+    /// Keep the word graph and the word frequencies for `TextRank::get_graph`, defaults to false.
+    pub keep_graph: bool,
+}
+
+impl Default for TextRankOptions {
+    fn default() -> Self {
+        Self {
+            window_size: 2,
+            damping_factor: 0.85,
+            tolerance: 0.00005,
+            max_iterations: usize::MAX,
+            convergence: Convergence::MaxAbsDelta,
+            sentence_bounded: false,
+            count_stop_words: false,
+            edge_direction: EdgeDirection::Undirected,
+            edge_weighting: EdgeWeighting::Count,
+            keep_graph: false,
+        }
+    }
+}
+
 /// The parameters to be used in the TextRank algorithm.
 pub enum TextRankParams<'a> {
     /// ## Arguments
@@ -58,6 +133,20 @@ pub enum TextRankParams<'a> {
         Tolerance,
         PhraseLength,
     ),
+
+    /// ## Arguments
+    /// 1. `text` - The text to be analyzed.
+    /// 2. `stop_words` - A list of stop words.
+    /// 3. `punctuation` - Optional list of punctuation symbols.
+    /// 4. `phrase_length` - Optional maximum length of the phrases to be ranked by the RAKE algorithm.
+    /// 5. `options` - The graph and power iteration options, see `TextRankOptions`.
+    WithOptions(
+        Text<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
+        PhraseLength,
+        TextRankOptions,
+    ),
 }
 
 impl<'a> TextRankParams<'a> {
@@ -65,9 +154,9 @@ impl<'a> TextRankParams<'a> {
     pub fn get_params(
         &self,
     ) -> (
-        Text,
-        Stopwords,
-        Punctuation,
+        Text<'a>,
+        Stopwords<'a>,
+        Punctuation<'a>,
         WindowSize,
         DampingFactor,
         Tolerance,
@@ -75,10 +164,10 @@ impl<'a> TextRankParams<'a> {
     ) {
         match self {
             TextRankParams::WithDefaults(text, stop_words) => {
-                (text, stop_words, None, 2, 0.85, 0.00005, None)
+                (*text, *stop_words, None, 2, 0.85, 0.00005, None)
             }
             TextRankParams::WithDefaultsAndPhraseLength(text, stop_words, phrase_length) => {
-                (text, stop_words, None, 2, 0.85, 0.00005, *phrase_length)
+                (*text, *stop_words, None, 2, 0.85, 0.00005, *phrase_length)
             }
             TextRankParams::All(
                 text,
@@ -89,14 +178,39 @@ impl<'a> TextRankParams<'a> {
                 min_diff,
                 phrase_length,
             ) => (
-                text,
-                stop_words,
+                *text,
+                *stop_words,
                 *punctuation,
                 *window_size,
                 *damping_factor,
                 *min_diff,
                 *phrase_length,
             ),
+            TextRankParams::WithOptions(text, stop_words, punctuation, phrase_length, options) => (
+                *text,
+                *stop_words,
+                *punctuation,
+                options.window_size,
+                options.damping_factor,
+                options.tolerance,
+                *phrase_length,
+            ),
+        }
+    }
+
+    /// Returns the graph and power iteration options to be used in the TextRank algorithm.
+    pub fn get_options(&self) -> TextRankOptions {
+        match self {
+            TextRankParams::WithOptions(_, _, _, _, options) => *options,
+            _ => {
+                let (_, _, _, window_size, damping_factor, tolerance, _) = self.get_params();
+                TextRankOptions {
+                    window_size,
+                    damping_factor,
+                    tolerance,
+                    ..TextRankOptions::default()
+                }
+            }
         }
     }
 }
@@ -21,7 +21,11 @@ pub mod tf_idf_params;
 use tf_idf_logic::TfIdfLogic;
 pub use tf_idf_params::{TextSplit, TfIdfParams};
 
-use crate::common::{get_ranked_scores, get_ranked_strings};
+use crate::{
+    common::{get_ranked_scores, get_ranked_strings, get_scores_above},
+    keyword_dedup::KeywordDedup,
+    normalization::ScoreNormalization,
+};
 
 pub struct TfIdf(HashMap<String, f32>);
 
@@ -32,6 +36,12 @@ impl TfIdf {
         Self(TfIdfLogic::build_tfidf(&documents))
     }
 
+    /// Normalises the word scores, so they can be compared across algorithms.
+    pub fn with_normalization(mut self, normalization: ScoreNormalization) -> Self {
+        self.0 = normalization.normalize(&self.0);
+        self
+    }
+
     /// Gets the score of a given word.
     pub fn get_score(&self, word: &str) -> f32 {
         *self.0.get(word).unwrap_or(&0.0)
@@ -47,6 +57,25 @@ impl TfIdf {
         get_ranked_scores(&self.0, n)
     }
 
+    /// Gets the words whose score is at least the threshold, sorted by score.
+    pub fn get_keywords_above(&self, threshold: f32) -> Vec<(String, f32)> {
+        get_scores_above(&self.0, threshold)
+    }
+
+    /// Gets the top n words with the highest score, after dropping the near-duplicates.
+    pub fn get_deduplicated_words(&self, n: usize, dedup: &KeywordDedup) -> Vec<String> {
+        dedup.get_ranked_strings(&self.0, n)
+    }
+
+    /// Gets the top n words with the highest score and their scores, after dropping the near-duplicates.
+    pub fn get_deduplicated_word_scores(
+        &self,
+        n: usize,
+        dedup: &KeywordDedup,
+    ) -> Vec<(String, f32)> {
+        dedup.get_ranked_scores(&self.0, n)
+    }
+
     /// Gets the word scores map.
     pub fn get_word_scores_map(&self) -> &HashMap<String, f32> {
         &self.0
@@ -171,6 +171,51 @@ impl Tokenizer {
             .collect::<Vec<String>>()
     }
 
+    /// Split text into unicode sentences of words, where each word is paired with its position
+    /// in the text counting the removed stop words (always synchronous even with parallel flag).
+    pub fn sync_split_into_positioned_words(&self) -> Vec<Vec<(usize, String)>> {
+        let special_char_regex = get_special_char_regex();
+        let mut sentence_starts = self
+            .text
+            .split_sentence_bound_indices()
+            .map(|(i, _)| i)
+            .skip(1)
+            .peekable();
+        let (mut sentences, last_sentence, _) = self.text.split_word_bound_indices().fold(
+            (Vec::<Vec<(usize, String)>>::new(), Vec::new(), 0_usize),
+            |(mut sentences, mut sentence, position), (i, w)| {
+                if sentence_starts.next_if(|start| *start <= i).is_some() {
+                    while sentence_starts.next_if(|start| *start <= i).is_some() {}
+
+                    if !sentence.is_empty() {
+                        sentences.push(sentence);
+                        sentence = Vec::new();
+                    }
+                }
+
+                let word = match &special_char_regex {
+                    Some(regex) => regex.replace_all(w.trim(), "").to_lowercase(),
+                    None => w.trim().to_lowercase(),
+                };
+
+                if is_punctuation(&word, &self.punctuation) {
+                    return (sentences, sentence, position);
+                }
+                if !self.stopwords.contains(&word) {
+                    sentence.push((position, word));
+                }
+
+                (sentences, sentence, position + 1)
+            },
+        );
+
+        if !last_sentence.is_empty() {
+            sentences.push(last_sentence);
+        }
+
+        sentences
+    }
+
     /// Split text into unicode sentences by splitting on punctuation.
     pub fn split_into_sentences(&self) -> Vec<String> {
         let special_char_regex = get_special_char_regex();
@@ -228,12 +273,41 @@ impl Tokenizer {
         self.basic_phrase_split(&special_char_regex, length)
     }
 
+    /// Split text into unicode sentences of phrases (always synchronous even with parallel flag).
+    pub fn sync_split_sentences_into_phrases(&self, length: PhraseLength) -> Vec<Vec<String>> {
+        let special_char_regex = get_special_char_regex();
+        self.text
+            .unicode_sentences()
+            .map(|s| self.split_text_into_phrases(s, &special_char_regex, length))
+            .filter(|phrases| !phrases.is_empty())
+            .collect()
+    }
+
+    /// Split text into paragraphs of phrases by splitting on newlines (always synchronous even with parallel flag).
+    pub fn sync_split_paragraphs_into_phrases(&self, length: PhraseLength) -> Vec<Vec<String>> {
+        let special_char_regex = get_special_char_regex();
+        self.text
+            .lines()
+            .map(|s| self.split_text_into_phrases(s, &special_char_regex, length))
+            .filter(|phrases| !phrases.is_empty())
+            .collect()
+    }
+
     fn basic_phrase_split(
         &self,
         special_char_regex: &Option<Regex>,
         length: Option<usize>,
     ) -> Vec<String> {
-        let (mut phrases, last_phrase) = self.text.split_word_bounds().fold(
+        self.split_text_into_phrases(&self.text, special_char_regex, length)
+    }
+
+    fn split_text_into_phrases(
+        &self,
+        text: &str,
+        special_char_regex: &Option<Regex>,
+        length: Option<usize>,
+    ) -> Vec<String> {
+        let (mut phrases, last_phrase) = text.split_word_bounds().fold(
             (Vec::<String>::new(), String::new()),
             |(phrases, acc), w| {
                 create_phrase(
@@ -264,28 +338,7 @@ impl Tokenizer {
         get_sentence_space_regex()
             .replace_all(&self.text, "¶")
             .par_split('¶')
-            .map(|s| {
-                let (mut phrases, last_phrase) = s.split_word_bounds().fold(
-                    (Vec::<String>::new(), String::new()),
-                    |(phrases, acc), w| {
-                        create_phrase(
-                            phrases,
-                            acc,
-                            w,
-                            special_char_regex,
-                            &self.punctuation,
-                            &self.stopwords,
-                            length,
-                        )
-                    },
-                );
-
-                if !last_phrase.is_empty() {
-                    phrases.push(last_phrase);
-                }
-
-                phrases
-            })
+            .map(|s| self.split_text_into_phrases(s, special_char_regex, length))
             .flatten()
             .collect::<Vec<String>>()
     }
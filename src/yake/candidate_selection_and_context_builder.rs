@@ -13,131 +13,318 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
 
-use std::{
-    cmp::min,
-    collections::{HashMap, HashSet, VecDeque},
-};
+use std::collections::{HashMap, HashSet};
 
 use unicode_segmentation::UnicodeSegmentation;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use super::sentences_builder::Sentence;
+use crate::stemmer::Stemmer;
+
+use super::{sentences_builder::Sentence, yake_params::YakeOptions};
+
+/// The tag of a word occurrence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tag {
+    /// A number.
+    Digit,
+    /// A word mixing letters and digits.
+    Alphanumeric,
+    /// A word mixing digits and uppercase letters.
+    AlphanumericAcronym,
+    /// A word without letters and digits, or with more than one punctuation mark.
+    Unusual,
+    /// A word written in uppercase.
+    Acronym,
+    /// A capitalised word that does not start the sentence.
+    ProperNoun,
+    /// Any other word.
+    Parsable,
+}
+
+pub struct Term {
+    pub tf: f32,
+    pub tf_acronym: f32,
+    pub tf_proper_noun: f32,
+    pub sentences: Vec<usize>,
+    pub is_stopword: bool,
+    pub is_valid: bool,
+}
+
+impl Term {
+    fn new(is_stopword: bool) -> Self {
+        Self {
+            tf: 0.0,
+            tf_acronym: 0.0,
+            tf_proper_noun: 0.0,
+            sentences: Vec::new(),
+            is_stopword,
+            is_valid: false,
+        }
+    }
+
+    fn add(&mut self, tag: Tag, is_discarded: bool, sentence: usize) {
+        self.tf += 1.0;
+
+        match tag {
+            Tag::Acronym | Tag::AlphanumericAcronym => self.tf_acronym += 1.0,
+            Tag::ProperNoun => self.tf_proper_noun += 1.0,
+            _ => {}
+        }
+
+        if self.sentences.last() != Some(&sentence) {
+            self.sentences.push(sentence);
+        }
+
+        self.is_valid |= !is_discarded;
+    }
+
+    #[cfg(feature = "parallel")]
+    fn merge(&mut self, mut other: Term) {
+        self.tf += other.tf;
+        self.tf_acronym += other.tf_acronym;
+        self.tf_proper_noun += other.tf_proper_noun;
+        self.sentences.append(&mut other.sentences);
+        self.is_valid |= other.is_valid;
+    }
+}
 
 pub struct Candidate<'a> {
     pub lexical_form: Vec<&'a str>,
-    pub surface_forms: Vec<Vec<&'a str>>,
+    pub surface_forms: HashMap<String, f32>,
+    pub tf: f32,
+    pub is_valid: bool,
 }
 
 impl<'a> Candidate<'a> {
-    pub fn new(stems: Vec<&'a str>) -> Self {
+    fn new(lexical_form: Vec<&'a str>) -> Self {
         Self {
-            lexical_form: stems,
-            surface_forms: Vec::new(),
+            lexical_form,
+            surface_forms: HashMap::new(),
+            tf: 0.0,
+            is_valid: false,
         }
     }
 
-    fn add(&mut self, words: Vec<&'a str>) {
-        self.surface_forms.push(words);
+    fn add(&mut self, surface_form: String, is_valid: bool) {
+        self.tf += 1.0;
+        self.is_valid |= is_valid;
+        *self.surface_forms.entry(surface_form).or_insert(0.0) += 1.0;
+    }
+
+    /// The most frequent lowercased surface form, the first alphabetically on ties.
+    pub fn get_surface_form(&self) -> String {
+        self.surface_forms
+            .iter()
+            .fold(
+                None,
+                |best: Option<(&String, f32)>, (form, count)| match best {
+                    Some((best_form, best_count))
+                        if best_count > *count || (best_count == *count && best_form < form) =>
+                    {
+                        best
+                    }
+                    _ => Some((form, *count)),
+                },
+            )
+            .map(|(form, _)| form.to_string())
+            .unwrap_or_default()
     }
 }
 
-type Candidates<'a> = HashMap<String, Candidate<'a>>;
-type DedupMap<'a> = HashMap<&'a str, f32>;
-pub type LeftRightContext<'a> = HashMap<&'a str, (Vec<&'a str>, Vec<&'a str>)>;
-pub type Occurrences<'a> = HashMap<&'a str, Vec<(&'a str, usize)>>;
+pub type Candidates<'a> = HashMap<String, Candidate<'a>>;
+pub type Terms<'a> = HashMap<&'a str, Term>;
+/// Number of times a term is followed by another term inside the window.
+pub type CoOccurrences<'a> = HashMap<&'a str, HashMap<&'a str, f32>>;
+
+#[derive(Default)]
+pub struct TextStatistics<'a> {
+    pub candidates: Candidates<'a>,
+    pub terms: Terms<'a>,
+    pub co_occurrences: CoOccurrences<'a>,
+}
+
+#[cfg(feature = "parallel")]
+impl<'a> TextStatistics<'a> {
+    fn merge(mut self, other: TextStatistics<'a>) -> Self {
+        other.candidates.into_iter().for_each(|(key, candidate)| {
+            self.candidates
+                .entry(key)
+                .and_modify(|entry| {
+                    entry.tf += candidate.tf;
+                    entry.is_valid |= candidate.is_valid;
+                    candidate.surface_forms.iter().for_each(|(form, count)| {
+                        *entry.surface_forms.entry(form.to_string()).or_insert(0.0) += count;
+                    });
+                })
+                .or_insert(candidate);
+        });
+        other.terms.into_iter().for_each(|(key, term)| {
+            match self.terms.get_mut(key) {
+                Some(entry) => entry.merge(term),
+                None => {
+                    self.terms.insert(key, term);
+                }
+            };
+        });
+        other.co_occurrences.into_iter().for_each(|(key, row)| {
+            let entry = self.co_occurrences.entry(key).or_default();
+            row.into_iter().for_each(|(other_key, count)| {
+                *entry.entry(other_key).or_insert(0.0) += count;
+            });
+        });
+        self
+    }
+}
 
 fn is_punctuation(word: &str, punctuation: &HashSet<&str>) -> bool {
-    word.is_empty() || ((word.graphemes(true).count() == 1) && punctuation.contains(word))
+    word.graphemes(true).all(|g| punctuation.contains(g))
 }
 
-fn is_invalid_word(word: &str, punctuation: &HashSet<&str>, stop_words: &HashSet<&str>) -> bool {
-    is_punctuation(word, punctuation) || stop_words.contains(word) || word.parse::<f32>().is_ok()
+fn get_tag(word: &str, position: usize, punctuation: &HashSet<&str>) -> Tag {
+    if word.replace(',', "").parse::<f64>().is_ok() {
+        return Tag::Digit;
+    }
+
+    let digits = word.chars().filter(|c| c.is_numeric()).count();
+    let letters = word.chars().filter(|c| c.is_alphabetic()).count();
+    let symbols = word
+        .graphemes(true)
+        .filter(|g| punctuation.contains(g))
+        .count();
+
+    if (digits == 0 && letters == 0) || symbols > 1 {
+        return Tag::Unusual;
+    }
+
+    let length = word.chars().count();
+    let uppercase = word.chars().filter(|c| c.is_uppercase()).count();
+
+    if digits > 0 {
+        // Codes such as "A100" are acronyms when all their letters are uppercase.
+        return if uppercase == letters {
+            Tag::AlphanumericAcronym
+        } else {
+            Tag::Alphanumeric
+        };
+    }
+
+    if uppercase == length {
+        Tag::Acronym
+    } else if uppercase == 1
+        && length > 1
+        && position > 0
+        && word.chars().next().is_some_and(char::is_uppercase)
+    {
+        Tag::ProperNoun
+    } else {
+        Tag::Parsable
+    }
 }
 
-fn process_sentences<'a, 'b>(
+struct SentenceProcessor<'a, 'b> {
     ngram: usize,
     window_size: usize,
     stop_words: &'b HashSet<&'a str>,
     punctuation: &'b HashSet<&'a str>,
-    mut candidates: Candidates<'a>,
-    mut dedup_map: DedupMap<'a>,
-    mut occurrences: Occurrences<'a>,
-    mut lr_contexts: LeftRightContext<'a>,
-    i: usize,
-    sentence: &'a Sentence<'a>,
-) -> (
-    Candidates<'a>,
-    DedupMap<'a>,
-    Occurrences<'a>,
-    LeftRightContext<'a>,
-) {
-    sentence.words.iter().enumerate().fold(
-        VecDeque::<(&str, usize)>::with_capacity(window_size + 1),
-        |mut buffer, (j, w1)| {
-            // Candidate Selection
-            (j + 1..=min(j + ngram, sentence.length)).for_each(|k: usize| {
-                let stems = sentence.stemmed[j..k]
-                    .iter()
-                    .map(|s| s.as_str())
-                    .collect::<Vec<&'a str>>();
-                if stems
-                    .iter()
-                    .any(|w| is_invalid_word(w, punctuation, stop_words))
-                {
-                    return;
-                }
+    options: &'b YakeOptions,
+}
 
-                let words = sentence.words[j..k]
-                    .iter()
-                    .map(|s| s.as_ref())
-                    .collect::<Vec<&'a str>>();
-                let key = stems.join(" ");
-                let entry = match candidates.get_mut(&key) {
-                    Some(entry) => entry,
-                    None => {
-                        if stems.len() > 1 {
-                            stems.iter().for_each(|w| {
-                                let entry = dedup_map.entry(*w).or_insert(0.0);
-                                *entry += 1.0;
-                            });
-                        }
-
-                        candidates.entry(key).or_insert(Candidate::new(stems))
-                    }
-                };
-                entry.add(words);
-            });
+impl<'a, 'b> SentenceProcessor<'a, 'b> {
+    /// Numbers and alphanumerics are only kept when enabled in the options.
+    fn is_discarded(&self, tag: Tag) -> bool {
+        match tag {
+            Tag::Digit => !self.options.keep_numbers,
+            Tag::Alphanumeric | Tag::AlphanumericAcronym => !self.options.keep_alphanumerics,
+            Tag::Unusual => true,
+            _ => false,
+        }
+    }
 
-            // Context Building
-            let key1 = sentence.stemmed[j].as_str();
-            let w1_str = w1.as_ref();
+    /// Stop words, and words with less than three characters besides punctuation, ignoring the plural "s".
+    fn is_stopword(&self, word: &str) -> bool {
+        let singular = Stemmer::Plural.stem(word);
 
-            if !is_invalid_word(key1, punctuation, stop_words) {
-                let entry = occurrences.entry(key1).or_default();
-                entry.push((w1_str, i));
-            }
+        self.stop_words.contains(word.to_lowercase().as_str())
+            || self.stop_words.contains(singular.as_str())
+            || singular
+                .graphemes(true)
+                .filter(|g| !self.punctuation.contains(g))
+                .count()
+                < 3
+    }
 
-            buffer.iter().for_each(|(w2, k)| {
-                let entry_1 = lr_contexts.entry(key1).or_insert((Vec::new(), Vec::new()));
-                entry_1.0.push(*w2);
-                let entry_2 = lr_contexts
-                    .entry(sentence.stemmed[*k].as_str())
-                    .or_insert((Vec::new(), Vec::new()));
-                entry_2.1.push(w1_str);
-            });
+    /// Selects the candidates and counts the co-occurrences of each block of words between punctuation marks.
+    fn process(
+        &self,
+        mut statistics: TextStatistics<'a>,
+        i: usize,
+        sentence: &'a Sentence<'a>,
+    ) -> TextStatistics<'a> {
+        let mut block = Vec::<(usize, bool)>::new();
+
+        sentence.words.iter().enumerate().for_each(|(j, word)| {
+            if is_punctuation(word, self.punctuation) {
+                block.clear();
+                return;
+            }
 
-            buffer.push_back((w1_str, j));
+            let tag = get_tag(word, j, self.punctuation);
+            let is_discarded = self.is_discarded(tag);
+            let term = sentence.stemmed[j].as_str();
+            statistics
+                .terms
+                .entry(term)
+                .or_insert_with(|| Term::new(self.is_stopword(word)))
+                .add(tag, is_discarded, i);
 
-            if buffer.len() > window_size {
-                buffer.pop_front();
+            // Context Building
+            if !is_discarded {
+                block[block.len().saturating_sub(self.window_size)..]
+                    .iter()
+                    .filter(|(_, other_discarded)| !other_discarded)
+                    .for_each(|(k, _)| {
+                        *statistics
+                            .co_occurrences
+                            .entry(sentence.stemmed[*k].as_str())
+                            .or_default()
+                            .entry(term)
+                            .or_insert(0.0) += 1.0;
+                    });
             }
 
-            buffer
-        },
-    );
-    (candidates, dedup_map, occurrences, lr_contexts)
+            block.push((j, is_discarded));
+
+            // Candidate Selection
+            (block.len().saturating_sub(self.ngram)..block.len()).for_each(|start| {
+                let words = &block[start..];
+                let lexical_form = words
+                    .iter()
+                    .map(|(k, _)| sentence.stemmed[*k].as_str())
+                    .collect::<Vec<&'a str>>();
+                let is_valid = words.iter().all(|(_, discarded)| !discarded)
+                    && !statistics.terms[lexical_form[0]].is_stopword
+                    && !statistics.terms[term].is_stopword;
+                let surface_form = words
+                    .iter()
+                    .map(|(k, _)| sentence.words[*k].to_lowercase())
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                // Candidates are only grouped by stems when a stemmer is chosen, as the reference keys them by surface form.
+                let key = match self.options.stemmer {
+                    Some(_) => lexical_form.join(" "),
+                    None => surface_form.to_string(),
+                };
+                statistics
+                    .candidates
+                    .entry(key)
+                    .or_insert_with(|| Candidate::new(lexical_form))
+                    .add(surface_form, is_valid);
+            });
+        });
+        statistics
+    }
 }
 
 pub struct CandidateSelectionAndContextBuilder;
@@ -149,122 +336,35 @@ impl<'a> CandidateSelectionAndContextBuilder {
         window_size: usize,
         stop_words: HashSet<&'a str>,
         punctuation: HashSet<&'a str>,
-    ) -> (
-        // Candidate Selection
-        Candidates<'a>,
-        DedupMap<'a>,
-        // Context Builder
-        Occurrences<'a>,
-        LeftRightContext<'a>,
-    ) {
+        options: &YakeOptions,
+    ) -> TextStatistics<'a> {
+        let processor = SentenceProcessor {
+            ngram,
+            window_size,
+            stop_words: &stop_words,
+            punctuation: &punctuation,
+            options,
+        };
+
         #[cfg(feature = "parallel")]
         {
             sentences
                 .par_iter()
                 .enumerate()
-                .fold(
-                    || {
-                        (
-                            Candidates::new(),
-                            DedupMap::new(),
-                            Occurrences::new(),
-                            LeftRightContext::new(),
-                        )
-                    },
-                    |(candidates, dedup_map, occurrences, lr_contexts), (i, sentence)| {
-                        process_sentences(
-                            ngram,
-                            window_size,
-                            &stop_words,
-                            &punctuation,
-                            candidates,
-                            dedup_map,
-                            occurrences,
-                            lr_contexts,
-                            i,
-                            sentence,
-                        )
-                    },
-                )
-                .reduce(
-                    || {
-                        (
-                            Candidates::new(),
-                            DedupMap::new(),
-                            Occurrences::new(),
-                            LeftRightContext::new(),
-                        )
-                    },
-                    |(mut candidates1, mut dedup_map1, mut occurrences1, mut lr_contexts1),
-                     (candidates2, dedup_map2, occurrences2, lr_contexts2)| {
-                        // Merge Candidates
-                        for (key, mut candidate2) in candidates2 {
-                            candidates1
-                                .entry(key)
-                                .and_modify(|candidate1| {
-                                    candidate1
-                                        .surface_forms
-                                        .append(&mut candidate2.surface_forms);
-                                })
-                                .or_insert(candidate2);
-                        }
-
-                        // Merge DedupMap
-                        for (key, value) in dedup_map2 {
-                            *dedup_map1.entry(key).or_insert(0.0) += value;
-                        }
-
-                        // Merge Occurrences
-                        for (key, mut occurrence_list) in occurrences2 {
-                            occurrences1
-                                .entry(key)
-                                .or_default()
-                                .append(&mut occurrence_list);
-                        }
-
-                        // Merge LeftRightContext
-                        for (key, (mut left_context, mut right_context)) in lr_contexts2 {
-                            lr_contexts1
-                                .entry(key)
-                                .or_insert_with(|| (Vec::new(), Vec::new()))
-                                .0
-                                .append(&mut left_context);
-                            lr_contexts1
-                                .entry(key)
-                                .or_insert_with(|| (Vec::new(), Vec::new()))
-                                .1
-                                .append(&mut right_context);
-                        }
-
-                        (candidates1, dedup_map1, occurrences1, lr_contexts1)
-                    },
-                )
+                .fold(TextStatistics::default, |statistics, (i, sentence)| {
+                    processor.process(statistics, i, sentence)
+                })
+                .reduce(TextStatistics::default, TextStatistics::merge)
         }
 
         #[cfg(not(feature = "parallel"))]
         {
-            sentences.iter().enumerate().fold(
-                (
-                    Candidates::new(),
-                    DedupMap::new(),
-                    Occurrences::new(),
-                    LeftRightContext::new(),
-                ),
-                |(candidates, dedup_map, occurrences, lr_contexts), (i, sentence)| {
-                    process_sentences(
-                        ngram,
-                        window_size,
-                        &stop_words,
-                        &punctuation,
-                        candidates,
-                        dedup_map,
-                        occurrences,
-                        lr_contexts,
-                        i,
-                        sentence,
-                    )
-                },
-            )
+            sentences
+                .iter()
+                .enumerate()
+                .fold(TextStatistics::default(), |statistics, (i, sentence)| {
+                    processor.process(statistics, i, sentence)
+                })
         }
     }
 }
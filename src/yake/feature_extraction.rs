@@ -13,160 +13,168 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
 
-use std::collections::{HashMap, HashSet};
-
-use unicode_segmentation::UnicodeSegmentation;
+use std::collections::HashMap;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use super::candidate_selection_and_context_builder::{LeftRightContext, Occurrences};
+use super::{
+    candidate_selection_and_context_builder::{CoOccurrences, Term, Terms},
+    term_scoring::TermScoring,
+};
+
+/// Number of distinct neighbours and total number of co-occurrences on one side of a term.
+type Context = (f32, f32);
+
+/// The features of a term, combined into its score.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TermFeatures {
+    /// Number of occurrences of the term.
+    pub tf: f32,
+    /// Occurrences as an acronym or a capitalised word, relative to the term frequency.
+    pub casing: f32,
+    /// Term frequency normalised by the mean and standard deviation of the term frequencies.
+    pub frequency: f32,
+    /// Grows with the median sentence where the term occurs.
+    pub position: f32,
+    /// Relatedness to context, grows with the number of distinct neighbours.
+    pub relatedness: f32,
+    /// Fraction of the sentences where the term occurs.
+    pub different: f32,
+    /// The score of the term, the lower the score the more relevant the term.
+    pub score: f32,
+}
 
-fn extract_feature<'a, 'b>(
-    contexts: &'a LeftRightContext<'a>,
-    word: &'b str,
-    occurrence: Vec<(&'b str, usize)>,
+struct TermStatistics {
     tf_mean: f32,
     tf_std: f32,
     tf_max: f32,
     sentences_len: f32,
-) -> (&'b str, f32) {
-    let tf = occurrence.len() as f32;
-
-    let (tf_upper, tf_capitalized) =
-        occurrence
-            .iter()
-            .fold((0.0_f32, 0.0_f32), |(tf_upper, tf_capitalized), (w, _)| {
-                (
-                    tf_upper
-                        + if w.graphemes(true).count() > 1 && &w.to_uppercase().as_str() == w {
-                            1.0
-                        } else {
-                            0.0
-                        },
-                    tf_capitalized
-                        + if w.chars().next().unwrap_or(' ').is_uppercase()
-                            && (w.graphemes(true).count() == 1
-                                || w.chars().skip(1).any(|c| c.is_lowercase()))
-                        {
-                            1.0
-                        } else {
-                            0.0
-                        },
-                )
-            });
+}
 
-    let casing = tf_upper.max(tf_capitalized) / (1.0 + tf.ln());
-    let frequency = tf / (tf_mean + tf_std + f32::EPSILON);
+/// Median of the distinct sentence indices where the term occurs.
+fn get_median(sentences: &[usize]) -> f32 {
+    let mut sorted = sentences.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let length = sorted.len();
 
-    let occ_len = occurrence.len();
-    let median = if occ_len == 0 {
+    if length == 0 {
         0.0
-    } else if occ_len == 1 {
-        occurrence[0].1 as f32
-    } else if occ_len % 2 == 0 {
-        occurrence[occ_len / 2].1 as f32
+    } else if length % 2 == 1 {
+        sorted[length / 2] as f32
     } else {
-        let mid = occ_len / 2;
-        (occurrence[mid].1 + occurrence[mid - 1].1) as f32 / 2.0
-    };
-
-    let position = (3.0 + median).ln().ln();
-
-    let left_right_context = contexts
-        .get(word)
-        .map(|(v1, v2)| (v1.as_slice(), v2.as_slice()))
-        .unwrap_or((&[], &[]));
+        (sorted[length / 2 - 1] + sorted[length / 2]) as f32 / 2.0
+    }
+}
 
-    let left_context_unique = left_right_context
-        .0
-        .iter()
-        .copied()
-        .collect::<HashSet<&str>>();
-    let wl = if !left_context_unique.is_empty() {
-        left_context_unique.len() as f32 / (left_right_context.0.len() as f32 + f32::EPSILON)
-    } else {
-        0.0_f32
-    };
+fn get_context_ratio(context: Option<&Context>) -> f32 {
+    match context {
+        Some((distinct, total)) if *total > 0.0 => distinct / total,
+        _ => 0.0,
+    }
+}
 
-    let right_context_unique = left_right_context
-        .1
-        .iter()
-        .copied()
-        .collect::<HashSet<&str>>();
-    let wr = if !right_context_unique.is_empty() {
-        right_context_unique.len() as f32 / (left_right_context.1.len() as f32 + f32::EPSILON)
-    } else {
-        0.0_f32
+fn extract_feature(
+    term: &Term,
+    left_context: Option<&Context>,
+    right_context: Option<&Context>,
+    statistics: &TermStatistics,
+    scoring: &TermScoring,
+) -> TermFeatures {
+    let tf = term.tf;
+    let casing = term.tf_acronym.max(term.tf_proper_noun) / (1.0 + tf.ln());
+    let frequency = tf / (statistics.tf_mean + statistics.tf_std).max(f32::EPSILON);
+    let position = (3.0 + get_median(&term.sentences)).ln().ln();
+    let wl = get_context_ratio(left_context);
+    let wr = get_context_ratio(right_context);
+    let relatedness = 1.0 + (wl + wr) * (tf / statistics.tf_max);
+    let different = term.sentences.len() as f32 / statistics.sentences_len.max(1.0);
+
+    let mut features = TermFeatures {
+        tf,
+        casing,
+        frequency,
+        position,
+        relatedness,
+        different,
+        score: 0.0,
     };
+    features.score = scoring.score(&features);
+    features
+}
 
-    let relatedness = 1.0 + ((wl + wr) * (tf / tf_max));
-
-    let unique_sentences = occurrence
-        .iter()
-        .map(|occ| occ.1)
-        .collect::<HashSet<usize>>();
-    let different = unique_sentences.len() as f32 / (sentences_len + f32::EPSILON);
-
-    (
-        word,
-        (relatedness * position) / (casing + (frequency / relatedness) + (different / relatedness)),
+fn get_contexts<'a>(
+    co_occurrences: &CoOccurrences<'a>,
+) -> (HashMap<&'a str, Context>, HashMap<&'a str, Context>) {
+    co_occurrences.iter().fold(
+        (HashMap::new(), HashMap::new()),
+        |(mut left, mut right), (word, row)| {
+            row.iter().for_each(|(other, count)| {
+                let entry = left.entry(*other).or_insert((0.0, 0.0));
+                entry.0 += 1.0;
+                entry.1 += count;
+            });
+            right.insert(*word, (row.len() as f32, row.values().sum()));
+            (left, right)
+        },
     )
 }
 
+fn get_term_statistics(terms: &Terms, sentences_len: f32) -> TermStatistics {
+    let valid_tfs = terms
+        .values()
+        .filter(|term| !term.is_stopword)
+        .map(|term| term.tf)
+        .collect::<Vec<f32>>();
+    let length = (valid_tfs.len() as f32).max(1.0);
+    let tf_mean = valid_tfs.iter().sum::<f32>() / length;
+    // The frequencies are whole numbers, so these sums do not depend on the order of the terms.
+    let tf_std = (valid_tfs.iter().map(|tf| tf * tf).sum::<f32>() / length - tf_mean * tf_mean)
+        .max(0.0)
+        .sqrt();
+
+    TermStatistics {
+        tf_mean,
+        tf_std,
+        tf_max: terms.values().fold(1.0_f32, |max, term| max.max(term.tf)),
+        sentences_len,
+    }
+}
+
 pub struct FeatureExtractor;
 
 impl<'a> FeatureExtractor {
+    /// Extracts the features of every term.
     pub fn score_words(
-        occurrences: Occurrences<'a>,
-        contexts: LeftRightContext<'a>,
+        terms: &Terms<'a>,
+        co_occurrences: &CoOccurrences<'a>,
         sentences_len: f32,
-    ) -> HashMap<&'a str, f32> {
-        let (tf_total, tf_max_u) = occurrences.values().fold((0, 0), |(tf_total, tf_max), v| {
-            let length = v.len();
-            (tf_total + length, tf_max.max(length))
-        });
-        let tf_max = tf_max_u as f32;
-        let tf_mean = tf_total as f32 / (occurrences.len() as f32 + f32::EPSILON);
-        let tf_std = occurrences
-            .values()
-            .fold(0.0_f32, |a, v| a + (v.len() as f32 - tf_mean).powi(2))
-            .sqrt();
+        scoring: &TermScoring,
+    ) -> HashMap<&'a str, TermFeatures> {
+        let statistics = get_term_statistics(terms, sentences_len);
+        let (left_contexts, right_contexts) = get_contexts(co_occurrences);
+        let score = |(word, term): (&&'a str, &Term)| {
+            (
+                *word,
+                extract_feature(
+                    term,
+                    left_contexts.get(word),
+                    right_contexts.get(word),
+                    &statistics,
+                    scoring,
+                ),
+            )
+        };
 
         #[cfg(feature = "parallel")]
         {
-            occurrences
-                .into_par_iter()
-                .map(|(word, occurrence)| {
-                    extract_feature(
-                        &contexts,
-                        word,
-                        occurrence,
-                        tf_mean,
-                        tf_std,
-                        tf_max,
-                        sentences_len,
-                    )
-                })
-                .collect()
+            terms.par_iter().map(score).collect()
         }
 
         #[cfg(not(feature = "parallel"))]
         {
-            occurrences
-                .into_iter()
-                .map(|(word, occurrence)| {
-                    extract_feature(
-                        &contexts,
-                        word,
-                        occurrence,
-                        tf_mean,
-                        tf_std,
-                        tf_max,
-                        sentences_len,
-                    )
-                })
-                .collect()
+            terms.iter().map(score).collect()
         }
     }
 }
@@ -13,54 +13,48 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
 
-use std::{cmp::min, collections::HashMap};
+use std::collections::HashMap;
 
 mod candidate_selection_and_context_builder;
 mod feature_extraction;
-mod levenshtein;
 mod sentences_builder;
+mod term_scoring;
 mod text_pre_processor;
 mod yake_logic;
 pub mod yake_params;
-pub use yake_params::YakeParams;
+pub use feature_extraction::TermFeatures;
+pub use term_scoring::{FeatureWeights, TermScoring, TermScoringFn};
+pub use yake_logic::CandidateDetails;
+pub use yake_params::{YakeOptions, YakeParams};
 
-use crate::common::{get_ranked_scores, get_ranked_strings, sort_ranked_map, PUNCTUATION};
+pub use crate::keyword_dedup::DedupStrategy;
 
-use levenshtein::Levenshtein;
-use yake_logic::YakeLogic;
+use crate::{
+    common::{get_ranked_scores, get_ranked_strings, get_scores_above, PUNCTUATION},
+    keyword_dedup::KeywordDedup,
+    normalization::ScoreNormalization,
+};
 
-fn build_ranked_keywords(vec: &mut Vec<String>, word: &str, threshold: f32) {
-    if vec
-        .iter()
-        .any(|w| Levenshtein::new(w, word).ratio() >= threshold)
-    {
-        return;
-    }
-    vec.push(word.to_string());
-}
-
-fn build_ranked_scores(vec: &mut Vec<(String, f32)>, word: &str, score: f32, threshold: f32) {
-    if vec
-        .iter()
-        .any(|(w, _)| Levenshtein::new(w, word).ratio() >= threshold)
-    {
-        return;
-    }
-    vec.push((word.to_string(), score));
-}
+use yake_logic::YakeLogic;
 
 pub struct Yake {
     keyword_rank: HashMap<String, f32>,
     term_rank: HashMap<String, f32>,
-    size: usize,
-    threshold: f32,
+    term_features: Option<HashMap<String, TermFeatures>>,
+    candidate_details: Option<HashMap<String, CandidateDetails>>,
+    dedup: KeywordDedup,
 }
 
 impl Yake {
     /// Create a new YAKE instance.
     pub fn new(params: YakeParams) -> Self {
+        Self::with_options(params, YakeOptions::default())
+    }
+
+    /// Create a new YAKE instance with the given options.
+    pub fn with_options(params: YakeParams, options: YakeOptions) -> Self {
         let (text, stop_words, puctuation, threshold, ngram, window_size) = params.get_params();
-        let (keyword_rank, term_rank) = YakeLogic::build_yake(
+        let ranks = YakeLogic::build_yake(
             text,
             stop_words.iter().map(|s| s.as_str()).collect(),
             match puctuation {
@@ -69,15 +63,33 @@ impl Yake {
             },
             ngram,
             window_size,
+            &options,
         );
         Self {
-            size: keyword_rank.len(),
-            keyword_rank,
-            term_rank,
-            threshold,
+            keyword_rank: ranks.keyword_rank,
+            term_rank: ranks.term_rank,
+            term_features: ranks.term_features,
+            candidate_details: ranks.candidate_details,
+            dedup: KeywordDedup {
+                threshold,
+                ..Default::default()
+            },
         }
     }
 
+    /// Sets the similarity function used to drop near-duplicate keywords, defaults to `DedupStrategy::Levenshtein`.
+    pub fn with_dedup_strategy(mut self, dedup_strategy: DedupStrategy) -> Self {
+        self.dedup.strategy = dedup_strategy;
+        self
+    }
+
+    /// Normalises the keyword and term scores, so they can be compared across algorithms.
+    pub fn with_normalization(mut self, normalization: ScoreNormalization) -> Self {
+        self.keyword_rank = normalization.normalize(&self.keyword_rank);
+        self.term_rank = normalization.normalize(&self.term_rank);
+        self
+    }
+
     /// Gets the score of a (n-gram terms) keyword.
     pub fn get_keyword_score(&self, keyword: &str) -> f32 {
         *self.keyword_rank.get(keyword).unwrap_or(&0.0)
@@ -90,42 +102,31 @@ impl Yake {
 
     /// Get the top n (n-gram terms) keywords with the highest score.
     pub fn get_ranked_keywords(&self, n: usize) -> Vec<String> {
-        let capacity = min(self.size, n);
-        let result = sort_ranked_map(&self.keyword_rank).into_iter().try_fold(
-            Vec::<String>::with_capacity(capacity),
-            |mut acc, (word, _)| {
-                if acc.len() == capacity {
-                    return Err(acc);
-                }
-                build_ranked_keywords(&mut acc, word, self.threshold);
-                Ok(acc)
-            },
-        );
-
-        match result {
-            Ok(v) => v,
-            Err(v) => v,
-        }
+        self.dedup.get_ranked_strings(&self.keyword_rank, n)
     }
 
     /// Gets the top n (n-gram terms) keywords with the highest score and their scores.
     pub fn get_ranked_keyword_scores(&self, n: usize) -> Vec<(String, f32)> {
-        let capacity = min(self.size, n);
-        let result = sort_ranked_map(&self.keyword_rank).into_iter().try_fold(
-            Vec::<(String, f32)>::with_capacity(capacity),
-            |mut acc, (word, score)| {
-                if acc.len() == capacity {
-                    return Err(acc);
-                }
-                build_ranked_scores(&mut acc, word, *score, self.threshold);
-                Ok(acc)
-            },
-        );
+        self.dedup.get_ranked_scores(&self.keyword_rank, n)
+    }
 
-        match result {
-            Ok(v) => v,
-            Err(v) => v,
-        }
+    /// Gets the (n-gram terms) keywords whose score is at least the threshold, sorted by score.
+    pub fn get_keywords_above(&self, threshold: f32) -> Vec<(String, f32)> {
+        get_scores_above(&self.keyword_rank, threshold)
+    }
+
+    /// Gets the top n (n-gram terms) keywords with the highest score, deduplicated with the given options instead of the YAKE threshold.
+    pub fn get_deduplicated_keywords(&self, n: usize, dedup: &KeywordDedup) -> Vec<String> {
+        dedup.get_ranked_strings(&self.keyword_rank, n)
+    }
+
+    /// Gets the top n (n-gram terms) keywords with the highest score and their scores, deduplicated with the given options.
+    pub fn get_deduplicated_keyword_scores(
+        &self,
+        n: usize,
+        dedup: &KeywordDedup,
+    ) -> Vec<(String, f32)> {
+        dedup.get_ranked_scores(&self.keyword_rank, n)
     }
 
     /// Gets the top n terms with the highest score.
@@ -147,4 +148,24 @@ impl Yake {
     pub fn get_term_scores_map(&self) -> &HashMap<String, f32> {
         &self.term_rank
     }
+
+    /// Gets the features of a term, when kept with the `keep_features` option.
+    pub fn get_term_features(&self, term: &str) -> Option<&TermFeatures> {
+        self.term_features.as_ref()?.get(term)
+    }
+
+    /// Gets the details of a (n-gram terms) keyword, when kept with the `keep_features` option.
+    pub fn get_candidate_details(&self, keyword: &str) -> Option<&CandidateDetails> {
+        self.candidate_details.as_ref()?.get(keyword)
+    }
+
+    /// Gets the term features map, when kept with the `keep_features` option.
+    pub fn get_term_features_map(&self) -> Option<&HashMap<String, TermFeatures>> {
+        self.term_features.as_ref()
+    }
+
+    /// Gets the (n-gram terms) keyword details map, when kept with the `keep_features` option.
+    pub fn get_candidate_details_map(&self) -> Option<&HashMap<String, CandidateDetails>> {
+        self.candidate_details.as_ref()
+    }
 }
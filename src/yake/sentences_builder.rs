@@ -13,47 +13,69 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
 
-use std::borrow::Cow;
-
-use regex::Regex;
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::common::get_special_char_regex;
+use crate::stemmer::Stemmer;
 
 pub struct Sentence<'a> {
-    pub words: Vec<Cow<'a, str>>,
+    pub words: Vec<&'a str>,
     pub stemmed: Vec<String>,
     pub length: usize,
 }
 
-impl<'a> Sentence<'a> {
-    pub fn new(s: &'a str, special_char_regex: &Option<Regex>) -> Self {
-        let words = s
-            .split_word_bounds()
-            .filter_map(|w| {
-                let trimmed = w.trim();
+/// Removes the contracted "'s" of a word, the contraction itself is not kept as a token.
+fn remove_contraction(word: &str) -> Option<&str> {
+    let word = ["'s", "'S", "\u{2019}s", "\u{2019}S"]
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix))
+        .unwrap_or(word);
+
+    if word.is_empty() || (word.starts_with('\'') && word.len() > 1) {
+        return None;
+    }
+
+    Some(word)
+}
 
-                if trimmed.is_empty() {
-                    return None;
-                }
+/// Splits a sentence into tokens, keeping hyphenated words such as "co-founder" as a single token.
+fn split_tokens(s: &str) -> Vec<&str> {
+    let bounds = s.split_word_bound_indices().collect::<Vec<(usize, &str)>>();
+    let is_word = |token: &str| token.chars().all(char::is_alphanumeric);
+    let mut tokens = Vec::<&str>::with_capacity(bounds.len());
+    let mut start = None::<usize>;
 
-                if let Some(regex) = special_char_regex {
-                    let value = regex.replace_all(trimmed, "");
+    bounds.iter().enumerate().for_each(|(i, (index, token))| {
+        let joins_next = bounds.get(i + 1).is_some_and(|(_, next)| {
+            is_word(token) && *next == "-"
+                || *token == "-" && is_word(next) && i > 0 && is_word(bounds[i - 1].1)
+        });
 
-                    if value.is_empty() {
-                        return None;
-                    }
+        match (start, joins_next) {
+            (None, true) => start = Some(*index),
+            (Some(_), true) => (),
+            (Some(first), false) => {
+                tokens.push(&s[first..index + token.len()]);
+                start = None;
+            }
+            (None, false) => tokens.push(token),
+        }
+    });
 
-                    Some(value)
-                } else {
-                    Some(trimmed.into())
-                }
-            })
-            .collect::<Vec<Cow<'a, str>>>();
+    tokens
+}
+
+impl<'a> Sentence<'a> {
+    pub fn new(s: &'a str, stemmer: Option<&Stemmer>) -> Self {
+        let stemmer = stemmer.unwrap_or(&Stemmer::Plural);
+        let words = split_tokens(s)
+            .into_iter()
+            .map(str::trim)
+            .filter_map(remove_contraction)
+            .collect::<Vec<&'a str>>();
         Self {
             stemmed: words
                 .iter()
-                .map(|w| w.to_lowercase())
+                .map(|w| stemmer.stem(w))
                 .collect::<Vec<String>>(),
             length: words.len(),
             words,
@@ -64,10 +86,10 @@ impl<'a> Sentence<'a> {
 pub struct SentencesBuilder;
 
 impl<'a> SentencesBuilder {
-    pub fn build_sentences(text: &'a str) -> Vec<Sentence<'a>> {
-        let special_char_regex = get_special_char_regex();
+    pub fn build_sentences(text: &'a str, stemmer: Option<&Stemmer>) -> Vec<Sentence<'a>> {
         text.unicode_sentences()
-            .map(|s| Sentence::new(s.trim(), &special_char_regex))
+            .map(|s| Sentence::new(s.trim(), stemmer))
+            .filter(|s| s.length > 0)
             .collect()
     }
 }
@@ -0,0 +1,97 @@
+// Copyright (C) 2024 Afonso Barracha
+//
+// Rust Keyword Extraction is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Rust Keyword Extraction is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
+
+use std::{fmt, sync::Arc};
+
+use super::feature_extraction::TermFeatures;
+
+/// The weights of the term features in the YAKE term score.
+///
+/// Position and relatedness are multiplied, so their weights are exponents and a weight of 0 switches
+/// them off. Casing, frequency and different sentences are added, so their weights are factors and a
+/// weight of 0 also switches them off, when all three are 0 the denominator is clamped to `f32::EPSILON`.
+/// All weights default to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeatureWeights {
+    pub casing: f32,
+    pub frequency: f32,
+    pub position: f32,
+    pub relatedness: f32,
+    pub different: f32,
+}
+
+impl Default for FeatureWeights {
+    fn default() -> Self {
+        Self {
+            casing: 1.0,
+            frequency: 1.0,
+            position: 1.0,
+            relatedness: 1.0,
+            different: 1.0,
+        }
+    }
+}
+
+impl FeatureWeights {
+    /// Weighted term score, the lower the score the more relevant the term.
+    pub fn score(&self, features: &TermFeatures) -> f32 {
+        let relatedness = features.relatedness.powf(self.relatedness);
+        let position = features.position.powf(self.position);
+
+        (relatedness * position)
+            / (self.casing * features.casing
+                + self.frequency * features.frequency / relatedness
+                + self.different * features.different / relatedness)
+                .max(f32::EPSILON)
+    }
+}
+
+/// A custom term score function of the term features.
+pub type TermScoringFn = Arc<dyn Fn(&TermFeatures) -> f32 + Send + Sync>;
+
+/// How the term features are combined into the term score.
+#[derive(Clone)]
+pub enum TermScoring {
+    /// The YAKE formula with weighted features.
+    Weighted(FeatureWeights),
+
+    /// A custom function of the features, the lower the returned score the more relevant the term.
+    Custom(TermScoringFn),
+}
+
+impl fmt::Debug for TermScoring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Weighted(weights) => f.debug_tuple("Weighted").field(weights).finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl Default for TermScoring {
+    fn default() -> Self {
+        Self::Weighted(FeatureWeights::default())
+    }
+}
+
+impl TermScoring {
+    /// Term score of the features.
+    pub fn score(&self, features: &TermFeatures) -> f32 {
+        match self {
+            Self::Weighted(weights) => weights.score(features),
+            Self::Custom(score) => score(features),
+        }
+    }
+}
@@ -13,21 +13,32 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
 
-use std::borrow::Cow;
-
-use crate::common::get_space_regex;
-
 pub struct TextPreProcessor;
 
-impl<'a> TextPreProcessor {
-    pub fn process_text(text: &'a str) -> Cow<'a, str> {
-        let space_regex = get_space_regex();
-        let trimmed_text = text.trim();
+fn starts_with_uppercase(line: &str) -> bool {
+    line.trim_start()
+        .chars()
+        .next()
+        .is_some_and(char::is_uppercase)
+}
+
+impl TextPreProcessor {
+    /// Joins the lines of the text, lines starting with an uppercase letter begin a new paragraph.
+    pub fn process_text(text: &str) -> String {
+        text.trim().lines().enumerate().fold(
+            String::with_capacity(text.len()),
+            |mut acc, (i, line)| {
+                if i > 0 {
+                    acc.push_str(if starts_with_uppercase(line) {
+                        "\n\n"
+                    } else {
+                        " "
+                    });
+                }
 
-        if let Some(regex) = space_regex {
-            regex.replace_all(trimmed_text, " ")
-        } else {
-            trimmed_text.into()
-        }
+                acc.push_str(&line.replace('\t', " "));
+                acc
+            },
+        )
     }
 }
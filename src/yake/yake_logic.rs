@@ -19,35 +19,98 @@ use std::collections::{HashMap, HashSet};
 use rayon::prelude::*;
 
 use super::{
-    candidate_selection_and_context_builder::{Candidate, CandidateSelectionAndContextBuilder},
-    feature_extraction::FeatureExtractor,
+    candidate_selection_and_context_builder::{
+        Candidate, CandidateSelectionAndContextBuilder, CoOccurrences, Terms,
+    },
+    feature_extraction::{FeatureExtractor, TermFeatures},
     sentences_builder::SentencesBuilder,
     text_pre_processor::TextPreProcessor,
+    yake_params::YakeOptions,
 };
 
+/// The details of a candidate keyword, combined into its score.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CandidateDetails {
+    /// Number of occurrences of the candidate.
+    pub tf: f32,
+    /// Product of the term scores, with the stop word weights.
+    pub product: f32,
+    /// Sum of the term scores, with the stop word weights.
+    pub sum: f32,
+    /// The score of the candidate, the lower the score the more relevant the candidate.
+    pub score: f32,
+}
+
+pub struct YakeRanks {
+    pub keyword_rank: HashMap<String, f32>,
+    pub term_rank: HashMap<String, f32>,
+    pub term_features: Option<HashMap<String, TermFeatures>>,
+    pub candidate_details: Option<HashMap<String, CandidateDetails>>,
+}
+
 pub struct YakeLogic;
 
-fn score_candidate<'a>(
-    dedup_hashmap: &'a HashMap<&'a str, f32>,
-    word_scores: &HashMap<&'a str, f32>,
-    mut acc: Vec<(String, f32)>,
-    max: f32,
-    k: String,
-    pc: Candidate<'a>,
+struct CandidateScorer<'a, 'b> {
+    terms: &'b Terms<'a>,
+    co_occurrences: &'b CoOccurrences<'a>,
+    term_features: &'b HashMap<&'a str, TermFeatures>,
+}
+
+impl<'a, 'b> CandidateScorer<'a, 'b> {
+    /// Probability of a term being followed by the next one, given the occurrences of `given`.
+    fn get_probability(&self, first: &str, second: &str, given: &str) -> f32 {
+        self.co_occurrences
+            .get(first)
+            .and_then(|row| row.get(second))
+            .map_or(0.0, |count| count / self.terms[given].tf)
+    }
+
+    fn score(&self, candidate: &Candidate<'a>) -> CandidateDetails {
+        let words = &candidate.lexical_form;
+        let (product, sum) =
+            words
+                .iter()
+                .enumerate()
+                .fold((1.0_f32, 0.0_f32), |(prod, sum), (i, word)| {
+                    if !self.terms[word].is_stopword {
+                        let weight = self.term_features[word].score;
+                        return (prod * weight, sum + weight);
+                    }
+
+                    // Stop words can only be inside a valid candidate, so both neighbours exist.
+                    let probability = self.get_probability(words[i - 1], word, words[i - 1])
+                        * self.get_probability(word, words[i + 1], words[i + 1]);
+                    (prod * (2.0 - probability), sum - (1.0 - probability))
+                });
+        // As in the reference, avoids a zero denominator.
+        let sum = if sum == -1.0 { 1.0 - f32::EPSILON } else { sum };
+
+        CandidateDetails {
+            tf: candidate.tf,
+            product,
+            sum,
+            score: product / ((1.0 + sum) * candidate.tf),
+        }
+    }
+
+    fn score_candidate(
+        &self,
+        (_, candidate): (String, Candidate<'a>),
+    ) -> Option<(String, CandidateDetails)> {
+        if !candidate.is_valid {
+            return None;
+        }
+
+        Some((candidate.get_surface_form(), self.score(&candidate)))
+    }
+}
+
+fn inverse_score(
+    (mut acc, max): (Vec<(String, f32)>, f32),
+    (key, score): (String, f32),
 ) -> (Vec<(String, f32)>, f32) {
-    let (prod, sum) = pc.lexical_form.iter().fold(
-        (*dedup_hashmap.get(k.as_str()).unwrap_or(&1.0), 0.0),
-        |acc, w| {
-            let weight = *word_scores.get(*w).unwrap_or(&0.0);
-
-            (acc.0 * weight, acc.1 + weight)
-        },
-    );
-    let tf = pc.surface_forms.len() as f32;
-    let sum = if sum == -1.0 { 1.0 - f32::EPSILON } else { sum };
-    let score = prod / (tf * (1.0 + sum));
     let inverse_score = 1.0 / score;
-    acc.push((k, inverse_score));
+    acc.push((key, inverse_score));
     (acc, max.max(inverse_score))
 }
 
@@ -58,90 +121,98 @@ impl YakeLogic {
         punctuation: HashSet<&str>,
         ngram: usize,
         window_size: usize,
-    ) -> (HashMap<String, f32>, HashMap<String, f32>) {
+        options: &YakeOptions,
+    ) -> YakeRanks {
         let text = TextPreProcessor::process_text(text);
-        let sentences = SentencesBuilder::build_sentences(&text);
-        let (candidates, dedup_hashmap, occurrences, lr_contexts) =
-            CandidateSelectionAndContextBuilder::select_candidates_and_build_context(
-                &sentences,
-                ngram,
-                window_size,
-                stop_words,
-                punctuation,
-            );
-        let word_scores =
-            FeatureExtractor::score_words(occurrences, lr_contexts, sentences.len() as f32);
-        (
-            Self::score_candidates(candidates, dedup_hashmap, &word_scores),
-            Self::score_terms(word_scores),
-        )
+        let sentences = SentencesBuilder::build_sentences(&text, options.stemmer.as_ref());
+        let statistics = CandidateSelectionAndContextBuilder::select_candidates_and_build_context(
+            &sentences,
+            ngram,
+            window_size,
+            stop_words,
+            punctuation,
+            options,
+        );
+        let term_features = FeatureExtractor::score_words(
+            &statistics.terms,
+            &statistics.co_occurrences,
+            sentences.len() as f32,
+            &options.term_scoring,
+        );
+        let candidate_details = Self::score_candidates(
+            statistics.candidates,
+            &CandidateScorer {
+                terms: &statistics.terms,
+                co_occurrences: &statistics.co_occurrences,
+                term_features: &term_features,
+            },
+        );
+        let term_features = term_features
+            .into_iter()
+            .filter(|(word, _)| {
+                let term = &statistics.terms[word];
+                term.is_valid && !term.is_stopword
+            })
+            .map(|(word, features)| (word.to_string(), features))
+            .collect::<Vec<(String, TermFeatures)>>();
+
+        YakeRanks {
+            keyword_rank: Self::rank_scores(
+                candidate_details
+                    .iter()
+                    .map(|(key, details)| (key.to_string(), details.score))
+                    .collect(),
+            ),
+            term_rank: Self::rank_scores(
+                term_features
+                    .iter()
+                    .map(|(key, features)| (key.to_string(), features.score))
+                    .collect(),
+            ),
+            term_features: options
+                .keep_features
+                .then(|| term_features.into_iter().collect()),
+            candidate_details: options
+                .keep_features
+                .then(|| candidate_details.into_iter().collect()),
+        }
     }
 
     fn score_candidates<'a>(
         candidates: HashMap<String, Candidate<'a>>,
-        dedup_hashmap: HashMap<&'a str, f32>,
-        word_scores: &HashMap<&'a str, f32>,
-    ) -> HashMap<String, f32> {
-        let candidates_len = candidates.len();
-
+        scorer: &CandidateScorer<'a, '_>,
+    ) -> Vec<(String, CandidateDetails)> {
         #[cfg(feature = "parallel")]
         {
-            let (vec_scores, max) = candidates
+            candidates
                 .into_par_iter()
-                .fold(
-                    || (Vec::<(String, f32)>::new(), f32::EPSILON),
-                    |(acc, max), (k, pc)| {
-                        score_candidate(&dedup_hashmap, word_scores, acc, max, k, pc)
-                    },
-                )
-                .reduce(
-                    || (Vec::with_capacity(candidates_len), f32::EPSILON),
-                    |(mut acc1, max1), (mut acc2, max2)| {
-                        acc1.append(&mut acc2);
-                        (acc1, max1.max(max2))
-                    },
-                );
-
-            vec_scores
-                .into_par_iter()
-                .map(|(k, v)| (k, v / max))
-                .collect::<HashMap<String, f32>>()
+                .filter_map(|candidate| scorer.score_candidate(candidate))
+                .collect()
         }
 
         #[cfg(not(feature = "parallel"))]
         {
-            let (vec_scores, max) = candidates.into_iter().fold(
-                (
-                    Vec::<(String, f32)>::with_capacity(candidates_len),
-                    f32::EPSILON,
-                ),
-                |(acc, max), (k, pc)| score_candidate(&dedup_hashmap, word_scores, acc, max, k, pc),
-            );
-
-            vec_scores
+            candidates
                 .into_iter()
-                .map(|(k, v)| (k, v / max))
-                .collect::<HashMap<String, f32>>()
+                .filter_map(|candidate| scorer.score_candidate(candidate))
+                .collect()
         }
     }
 
-    fn score_terms(word_scores: HashMap<&str, f32>) -> HashMap<String, f32> {
-        let word_scores_len = word_scores.len();
+    /// Inverts the scores, so the higher the more relevant, and normalises them by the maximum.
+    fn rank_scores(scores: Vec<(String, f32)>) -> HashMap<String, f32> {
+        let scores_len = scores.len();
 
         #[cfg(feature = "parallel")]
         {
-            let (vec_scores, max) = word_scores
+            let (vec_scores, max) = scores
                 .into_par_iter()
                 .fold(
                     || (Vec::<(String, f32)>::new(), f32::EPSILON),
-                    |(mut acc, max), (k, score)| {
-                        let inverse_score = 1.0 / score;
-                        acc.push((k.to_string(), inverse_score));
-                        (acc, max.max(inverse_score))
-                    },
+                    inverse_score,
                 )
                 .reduce(
-                    || (Vec::with_capacity(word_scores_len), f32::EPSILON),
+                    || (Vec::with_capacity(scores_len), f32::EPSILON),
                     |(mut acc1, max1), (mut acc2, max2)| {
                         acc1.append(&mut acc2);
                         (acc1, max1.max(max2))
@@ -156,16 +227,12 @@ impl YakeLogic {
 
         #[cfg(not(feature = "parallel"))]
         {
-            let (vec_scores, max) = word_scores.into_iter().fold(
+            let (vec_scores, max) = scores.into_iter().fold(
                 (
-                    Vec::<(String, f32)>::with_capacity(word_scores_len),
+                    Vec::<(String, f32)>::with_capacity(scores_len),
                     f32::EPSILON,
                 ),
-                |(mut acc, max), (k, score)| {
-                    let inverse_score = 1.0 / score;
-                    acc.push((k.to_string(), inverse_score));
-                    (acc, max.max(inverse_score))
-                },
+                inverse_score,
             );
 
             vec_scores
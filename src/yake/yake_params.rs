@@ -13,11 +13,34 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with Rust Keyword Extraction. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::common::{Punctuation, Stopwords, Text, WindowSize};
+use crate::{
+    common::{Punctuation, Stopwords, Text, WindowSize},
+    stemmer::Stemmer,
+};
+
+use super::term_scoring::TermScoring;
 
 type Threshold = f32;
 type Ngram = usize;
 
+/// The options of the YAKE algorithm.
+#[derive(Clone, Debug, Default)]
+pub struct YakeOptions {
+    /// Keep the features of each term and the details of each candidate, defaults to false.
+    pub keep_features: bool,
+    /// How the term features are combined into the term score, defaults to the unweighted YAKE formula.
+    pub term_scoring: TermScoring,
+    /// Keep numbers, such as years and model numbers, as candidate words, defaults to false.
+    pub keep_numbers: bool,
+    /// Keep words mixing letters and digits, such as product codes, as candidate words, defaults to false.
+    pub keep_alphanumerics: bool,
+    /// The stemmer that groups candidates sharing the same stems, such as singular and plural forms, defaults to `None`.
+    ///
+    /// Without a stemmer, candidates are keyed by their lowercased surface form and terms by their singular form,
+    /// as in the reference YAKE. With a stemmer, both candidates and terms are keyed by their stems.
+    pub stemmer: Option<Stemmer>,
+}
+
 pub enum YakeParams<'a> {
     /// ## Arguments
     /// 1. `text` - The text to be analyzed.